@@ -0,0 +1,63 @@
+use advent_of_code_2021::y2021::day18::{FlatNumber, Number};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fmt::Display;
+use std::ops::Add;
+use std::str::FromStr;
+
+const NUMBERS: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+/// Largest magnitude of the sum of any two different numbers, as in part 2.
+fn max_pair_magnitude<N, R, M>(numbers: &[N], reduce: R, magnitude: M) -> u64
+where
+    N: Clone + Add<Output = N>,
+    R: Fn(&mut N),
+    M: Fn(&N) -> u64,
+{
+    let mut max_magnitude = 0;
+    for (i, lhs) in numbers.iter().enumerate() {
+        for (k, rhs) in numbers.iter().enumerate() {
+            if i != k {
+                let mut number = lhs.clone() + rhs.clone();
+                reduce(&mut number);
+                max_magnitude = max_magnitude.max(magnitude(&number));
+            }
+        }
+    }
+    max_magnitude
+}
+
+fn parse<N: FromStr>(input: &str) -> Vec<N>
+where
+    N::Err: Display,
+{
+    input
+        .lines()
+        .map(|line| line.parse().unwrap_or_else(|e| panic!("{}", e)))
+        .collect()
+}
+
+fn all_pairs(c: &mut Criterion) {
+    let trees: Vec<Number> = parse(NUMBERS);
+    let flats: Vec<FlatNumber> = parse(NUMBERS);
+
+    let mut group = c.benchmark_group("day18 all pairs");
+    group.bench_function("tree", |b| {
+        b.iter(|| max_pair_magnitude(black_box(&trees), Number::reduce, Number::magnitude))
+    });
+    group.bench_function("flat", |b| {
+        b.iter(|| max_pair_magnitude(black_box(&flats), FlatNumber::reduce, FlatNumber::magnitude))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, all_pairs);
+criterion_main!(benches);
@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code_2021::solution::find_solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = find_solution(2021, 8).unwrap().parse(input);
+});
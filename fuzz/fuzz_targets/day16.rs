@@ -0,0 +1,13 @@
+#![no_main]
+
+use advent_of_code_2021::solution::find_solution;
+use advent_of_code_2021::y2021::day16::{part_2, Packet, PacketReader};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Packet::decode(input);
+    let _ = find_solution(2021, 16).unwrap().parse(input);
+    // Evaluation must not overflow, whatever the literals.
+    let _ = part_2(input);
+    let _ = PacketReader::new(input.as_bytes()).count();
+});
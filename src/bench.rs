@@ -0,0 +1,135 @@
+use crate::memory::{self, Usage};
+use advent_of_code_2021::error::Result;
+use advent_of_code_2021::solution::Solution;
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort_unstable();
+
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Timing {
+    pub answer: String,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Allocations of the first run, when they are counted.
+    pub memory: Option<Usage>,
+}
+
+/// Runs the part `runs` times and collects parsing and solving times.
+///
+/// Parts always parse their input, so every run parses the input on its own first and
+/// the solving time of the run is the time of the part reduced by that parsing time.
+pub fn measure(solution: &dyn Solution, part: u16, input: &str, runs: usize) -> Result<Timing> {
+    let runs = runs.max(1);
+
+    let mut answer = String::new();
+    let mut memory = None;
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut solve_samples = Vec::with_capacity(runs);
+    for run in 0..runs {
+        let start = Instant::now();
+        solution.parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let (result, usage) = memory::measure(|| solution.run_part(part, input));
+        let elapsed = start.elapsed();
+        answer = result?;
+        if run == 0 {
+            memory = usage;
+        }
+
+        parse_samples.push(parse);
+        solve_samples.push(elapsed.saturating_sub(parse));
+    }
+
+    Ok(Timing {
+        answer,
+        parse: Stats::from_samples(parse_samples),
+        solve: Stats::from_samples(solve_samples),
+        memory,
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        n if n < 1_000 => format!("{}ns", n),
+        n if n < 1_000_000 => format!("{:.2}µs", n as f64 / 1e3),
+        n if n < 1_000_000_000 => format!("{:.2}ms", n as f64 / 1e6),
+        n => format!("{:.2}s", n as f64 / 1e9),
+    }
+}
+
+/// Header of the summary table, memory columns are present only when allocations are counted.
+pub fn table_header() -> String {
+    let mut header = format!(
+        "{:>3} {:>4}  {:>10} {:>10} {:>10}  {:>10} {:>10} {:>10}",
+        "day", "part", "parse min", "median", "max", "solve min", "median", "max"
+    );
+    if memory::is_enabled() {
+        header.push_str(&format!("  {:>10} {:>10}", "peak mem", "total mem"));
+    }
+    header
+}
+
+pub fn table_row(day: u16, part: u16, timing: &Timing) -> String {
+    let mut row = format!(
+        "{:>3} {:>4}  {:>10} {:>10} {:>10}  {:>10} {:>10} {:>10}",
+        day,
+        part,
+        format_duration(timing.parse.min),
+        format_duration(timing.parse.median),
+        format_duration(timing.parse.max),
+        format_duration(timing.solve.min),
+        format_duration(timing.solve.median),
+        format_duration(timing.solve.max),
+    );
+    if let Some(usage) = timing.memory {
+        row.push_str(&format!(
+            "  {:>10} {:>10}",
+            memory::format_bytes(usage.peak),
+            memory::format_bytes(usage.total)
+        ));
+    }
+    row
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bench::{format_duration, Stats};
+    use std::time::Duration;
+
+    #[test]
+    fn test() {
+        let stats = Stats::from_samples(vec![
+            Duration::from_millis(5),
+            Duration::from_millis(1),
+            Duration::from_millis(9),
+            Duration::from_millis(3),
+            Duration::from_millis(7),
+        ]);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.max, Duration::from_millis(9));
+
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2345)), "2.35s");
+    }
+}
@@ -0,0 +1,783 @@
+use crate::output::Format;
+use advent_of_code_2021::solution::DEFAULT_YEAR;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::thread;
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+
+pub const USAGE: &str = "Usage:
+    advent-of-code-2021 run <DAY> [PART] [--input <FILE>] [--time] [--trace] [--format <FORMAT>]
+    advent-of-code-2021 run --all [--jobs <N>] [--time] [--trace] [--format <FORMAT>]
+    advent-of-code-2021 bench [DAY] [PART] [--input <FILE>] [--runs <N>]
+    advent-of-code-2021 verify [DAY] [PART] [--record]
+    advent-of-code-2021 submit <DAY> <PART> [--input <FILE> | --answer <ANSWER>]
+    advent-of-code-2021 watch <DAY> [PART] [--input <FILE>]
+    advent-of-code-2021 new <DAY>
+    advent-of-code-2021 migrate
+    advent-of-code-2021 <DAY> <PART>
+
+Options:
+    --year <YEAR>     Event to select days of, 2021 by default
+    --all             Run all parts of all days
+    --input <FILE>    Read puzzle input from FILE instead of the default one, use - for stdin
+    --jobs <N>        Run up to N parts in parallel, one per CPU by default, results are
+                      still printed in order
+    --time            Print parsing and solving time of each part
+    --trace           Print time spent in slow solvers and their counters to stderr
+    --format <FORMAT> Output format of run results: text, json, csv or tsv
+    --runs <N>        Number of runs of each part when benchmarking, 10 by default
+    --record          Store answers of parts without an expected answer in outputs/
+    --answer <ANSWER> Submit ANSWER instead of solving the part
+    -h, --help        Print this message";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Submit(SubmitArgs),
+    Watch(WatchArgs),
+    /// Generates the module of a new day.
+    New {
+        year: u16,
+        day: u16,
+    },
+    /// Moves inputs and expected answers stored at their legacy location under the
+    /// directory of the year.
+    Migrate {
+        year: u16,
+    },
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub year: u16,
+    pub target: Target,
+    pub input: Option<Input>,
+    pub time: bool,
+    pub trace: bool,
+    pub format: Format,
+    pub jobs: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub run: RunArgs,
+    pub runs: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub year: u16,
+    pub target: Target,
+    pub record: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubmitArgs {
+    pub year: u16,
+    pub day: u16,
+    pub part: u16,
+    pub input: Option<Input>,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WatchArgs {
+    pub year: u16,
+    pub day: u16,
+    pub part: Option<u16>,
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Target {
+    All,
+    Day { day: u16, part: Option<u16> },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnexpectedArgument(String),
+    MissingValue(String),
+    InvalidNumber(String),
+    MissingDay,
+    InputWithAll,
+    InvalidFormat(String),
+    NoJobs,
+    MissingPart,
+    WatchStdin,
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::MissingCommand => write!(f, "no command provided"),
+            CliError::UnknownCommand(cmd) => write!(f, "unknown command: {}", cmd),
+            CliError::UnexpectedArgument(arg) => write!(f, "unexpected argument: {}", arg),
+            CliError::MissingValue(opt) => write!(f, "missing value for {}", opt),
+            CliError::InvalidNumber(val) => write!(f, "expected a number, got: {}", val),
+            CliError::MissingDay => write!(f, "provide DAY or --all"),
+            CliError::InputWithAll => write!(f, "--input cannot be used together with --all"),
+            CliError::InvalidFormat(format) => write!(f, "unknown format: {}", format),
+            CliError::NoJobs => write!(f, "--jobs must be at least 1"),
+            CliError::MissingPart => write!(f, "provide DAY and PART"),
+            CliError::WatchStdin => write!(f, "stdin cannot be watched, use --input <FILE>"),
+        }
+    }
+}
+
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        None => Err(CliError::MissingCommand),
+        Some("-h") | Some("--help") | Some("help") => Ok(Command::Help),
+        Some("run") => parse_run(args, vec![]),
+        Some("bench") => parse_bench(args),
+        Some("verify") => parse_verify(args),
+        Some("submit") => parse_submit(args),
+        Some("watch") => parse_watch(args),
+        Some("new") => parse_new(args),
+        Some("migrate") => parse_migrate(args),
+        // Keep supporting plain `[DAY] [TASK]` invocation.
+        Some(day) if day.parse::<u16>().is_ok() => parse_run(args, vec![day.to_string()]),
+        Some(other) => Err(CliError::UnknownCommand(other.to_string())),
+    }
+}
+
+#[derive(Default)]
+struct Options {
+    positional: Vec<String>,
+    year: Option<u16>,
+    all: bool,
+    input: Option<Input>,
+    time: bool,
+    trace: bool,
+    runs: Option<usize>,
+    format: Option<Format>,
+    jobs: Option<usize>,
+    record: bool,
+    answer: Option<String>,
+    help: bool,
+}
+
+fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+    positional: Vec<String>,
+) -> Result<Options, CliError> {
+    let mut opts = Options {
+        positional,
+        ..Options::default()
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                opts.year = Some(parse_number(&value)?);
+            }
+            "--all" => opts.all = true,
+            "--record" => opts.record = true,
+            "--time" | "-t" => opts.time = true,
+            "--trace" => opts.trace = true,
+            "--input" | "-i" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                opts.input = Some(match value.as_str() {
+                    "-" => Input::Stdin,
+                    file => Input::File(PathBuf::from(file)),
+                });
+            }
+            "--runs" | "-n" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                opts.runs = Some(parse_number(&value)?);
+            }
+            "--format" | "-f" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                opts.format = Some(value.parse().map_err(CliError::InvalidFormat)?);
+            }
+            "--answer" | "-a" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                opts.answer = Some(value);
+            }
+            "--jobs" | "-j" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                opts.jobs = Some(parse_number(&value)?);
+            }
+            "-h" | "--help" => opts.help = true,
+            other if other.starts_with('-') => {
+                return Err(CliError::UnexpectedArgument(other.to_string()))
+            }
+            _ => opts.positional.push(arg),
+        }
+    }
+
+    Ok(opts)
+}
+
+fn parse_run<I: Iterator<Item = String>>(
+    args: I,
+    positional: Vec<String>,
+) -> Result<Command, CliError> {
+    let opts = parse_options(args, positional)?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    if opts.runs.is_some() {
+        return Err(CliError::UnexpectedArgument("--runs".to_string()));
+    }
+    if opts.record {
+        return Err(CliError::UnexpectedArgument("--record".to_string()));
+    }
+    if opts.answer.is_some() {
+        return Err(CliError::UnexpectedArgument("--answer".to_string()));
+    }
+    if opts.jobs == Some(0) {
+        return Err(CliError::NoJobs);
+    }
+
+    let target =
+        parse_target(opts.all, &opts.positional, &opts.input)?.ok_or(CliError::MissingDay)?;
+
+    Ok(Command::Run(RunArgs {
+        year: opts.year.unwrap_or(DEFAULT_YEAR),
+        target,
+        input: opts.input,
+        time: opts.time,
+        trace: opts.trace,
+        format: opts.format.unwrap_or(Format::Text),
+        jobs: opts.jobs.unwrap_or_else(default_jobs),
+    }))
+}
+
+fn parse_bench<I: Iterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let opts = parse_options(args, vec![])?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    if opts.format.is_some() {
+        return Err(CliError::UnexpectedArgument("--format".to_string()));
+    }
+    if opts.record {
+        return Err(CliError::UnexpectedArgument("--record".to_string()));
+    }
+    // Parts running in parallel would skew each other's timings.
+    if opts.jobs.is_some() {
+        return Err(CliError::UnexpectedArgument("--jobs".to_string()));
+    }
+    if opts.trace {
+        return Err(CliError::UnexpectedArgument("--trace".to_string()));
+    }
+    if opts.answer.is_some() {
+        return Err(CliError::UnexpectedArgument("--answer".to_string()));
+    }
+
+    // Benchmark all days when none is selected.
+    let target = parse_target(opts.all, &opts.positional, &opts.input)?.unwrap_or(Target::All);
+
+    Ok(Command::Bench(BenchArgs {
+        run: RunArgs {
+            year: opts.year.unwrap_or(DEFAULT_YEAR),
+            target,
+            input: opts.input,
+            time: true,
+            trace: false,
+            format: Format::Text,
+            jobs: 1,
+        },
+        runs: opts.runs.unwrap_or(DEFAULT_BENCH_RUNS),
+    }))
+}
+
+fn parse_verify<I: Iterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let opts = parse_options(args, vec![])?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    // Answers are verified against default inputs only.
+    let unexpected = [
+        (opts.input.is_some(), "--input"),
+        (opts.time, "--time"),
+        (opts.trace, "--trace"),
+        (opts.runs.is_some(), "--runs"),
+        (opts.format.is_some(), "--format"),
+        (opts.jobs.is_some(), "--jobs"),
+        (opts.answer.is_some(), "--answer"),
+    ];
+    if let Some((_, opt)) = unexpected.iter().find(|(set, _)| *set) {
+        return Err(CliError::UnexpectedArgument(opt.to_string()));
+    }
+
+    // Verify all days when none is selected.
+    let target = parse_target(opts.all, &opts.positional, &None)?.unwrap_or(Target::All);
+
+    Ok(Command::Verify(VerifyArgs {
+        year: opts.year.unwrap_or(DEFAULT_YEAR),
+        target,
+        record: opts.record,
+    }))
+}
+
+fn parse_submit<I: Iterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let opts = parse_options(args, vec![])?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    let unexpected = [
+        (opts.all, "--all"),
+        (opts.time, "--time"),
+        (opts.trace, "--trace"),
+        (opts.runs.is_some(), "--runs"),
+        (opts.format.is_some(), "--format"),
+        (opts.jobs.is_some(), "--jobs"),
+        (opts.record, "--record"),
+        // The input is not needed when the answer is given.
+        (opts.input.is_some() && opts.answer.is_some(), "--input"),
+    ];
+    if let Some((_, opt)) = unexpected.iter().find(|(set, _)| *set) {
+        return Err(CliError::UnexpectedArgument(opt.to_string()));
+    }
+
+    match parse_target(false, &opts.positional, &opts.input)? {
+        Some(Target::Day {
+            day,
+            part: Some(part),
+        }) => Ok(Command::Submit(SubmitArgs {
+            year: opts.year.unwrap_or(DEFAULT_YEAR),
+            day,
+            part,
+            input: opts.input,
+            answer: opts.answer,
+        })),
+        _ => Err(CliError::MissingPart),
+    }
+}
+
+fn parse_watch<I: Iterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let opts = parse_options(args, vec![])?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    let unexpected = [
+        (opts.all, "--all"),
+        (opts.time, "--time"),
+        (opts.trace, "--trace"),
+        (opts.runs.is_some(), "--runs"),
+        (opts.format.is_some(), "--format"),
+        (opts.jobs.is_some(), "--jobs"),
+        (opts.record, "--record"),
+        (opts.answer.is_some(), "--answer"),
+    ];
+    if let Some((_, opt)) = unexpected.iter().find(|(set, _)| *set) {
+        return Err(CliError::UnexpectedArgument(opt.to_string()));
+    }
+
+    let input = match opts.input {
+        Some(Input::Stdin) => return Err(CliError::WatchStdin),
+        Some(Input::File(path)) => Some(path),
+        None => None,
+    };
+    match parse_target(false, &opts.positional, &None)? {
+        Some(Target::Day { day, part }) => Ok(Command::Watch(WatchArgs {
+            year: opts.year.unwrap_or(DEFAULT_YEAR),
+            day,
+            part,
+            input,
+        })),
+        _ => Err(CliError::MissingDay),
+    }
+}
+
+fn parse_new<I: Iterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let opts = parse_options(args, vec![])?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    let unexpected = [
+        (opts.all, "--all"),
+        (opts.input.is_some(), "--input"),
+        (opts.time, "--time"),
+        (opts.trace, "--trace"),
+        (opts.runs.is_some(), "--runs"),
+        (opts.format.is_some(), "--format"),
+        (opts.jobs.is_some(), "--jobs"),
+        (opts.record, "--record"),
+        (opts.answer.is_some(), "--answer"),
+    ];
+    if let Some((_, opt)) = unexpected.iter().find(|(set, _)| *set) {
+        return Err(CliError::UnexpectedArgument(opt.to_string()));
+    }
+
+    match &opts.positional[..] {
+        [] => Err(CliError::MissingDay),
+        [day] => Ok(Command::New {
+            year: opts.year.unwrap_or(DEFAULT_YEAR),
+            day: parse_number(day)?,
+        }),
+        [_, arg, ..] => Err(CliError::UnexpectedArgument(arg.clone())),
+    }
+}
+
+fn parse_migrate<I: Iterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let opts = parse_options(args, vec![])?;
+    if opts.help {
+        return Ok(Command::Help);
+    }
+    let unexpected = [
+        (opts.all, "--all"),
+        (opts.input.is_some(), "--input"),
+        (opts.time, "--time"),
+        (opts.trace, "--trace"),
+        (opts.runs.is_some(), "--runs"),
+        (opts.format.is_some(), "--format"),
+        (opts.jobs.is_some(), "--jobs"),
+        (opts.record, "--record"),
+        (opts.answer.is_some(), "--answer"),
+    ];
+    if let Some((_, opt)) = unexpected.iter().find(|(set, _)| *set) {
+        return Err(CliError::UnexpectedArgument(opt.to_string()));
+    }
+
+    match opts.positional.first() {
+        None => Ok(Command::Migrate {
+            year: opts.year.unwrap_or(DEFAULT_YEAR),
+        }),
+        Some(arg) => Err(CliError::UnexpectedArgument(arg.clone())),
+    }
+}
+
+/// Parts run in parallel unless limited by `--jobs`, one per available CPU.
+fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+fn parse_target(
+    all: bool,
+    positional: &[String],
+    input: &Option<Input>,
+) -> Result<Option<Target>, CliError> {
+    let target = match (all, positional) {
+        (true, []) if input.is_some() => return Err(CliError::InputWithAll),
+        (true, []) => Target::All,
+        (true, [arg, ..]) => return Err(CliError::UnexpectedArgument(arg.clone())),
+        (false, []) => return Ok(None),
+        (false, [day]) => Target::Day {
+            day: parse_number(day)?,
+            part: None,
+        },
+        (false, [day, part]) => Target::Day {
+            day: parse_number(day)?,
+            part: Some(parse_number(part)?),
+        },
+        (false, [_, _, arg, ..]) => return Err(CliError::UnexpectedArgument(arg.clone())),
+    };
+
+    Ok(Some(target))
+}
+
+fn parse_number<T: FromStr>(s: &str) -> Result<T, CliError> {
+    s.parse()
+        .map_err(|_| CliError::InvalidNumber(s.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cli::{
+        default_jobs, parse_args, BenchArgs, CliError, Command, Input, RunArgs, SubmitArgs, Target,
+        VerifyArgs, WatchArgs,
+    };
+    use crate::output::Format;
+    use std::path::PathBuf;
+
+    fn parse(args: &str) -> Result<Command, CliError> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test() {
+        let test_cases = vec![
+            (
+                "16 2",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::Day {
+                        day: 16,
+                        part: Some(2),
+                    },
+                    input: None,
+                    time: false,
+                    trace: false,
+                    format: Format::Text,
+                    jobs: default_jobs(),
+                })),
+            ),
+            (
+                "run 3",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::Day { day: 3, part: None },
+                    input: None,
+                    time: false,
+                    trace: false,
+                    format: Format::Text,
+                    jobs: default_jobs(),
+                })),
+            ),
+            (
+                "run 3 1 --input other.txt --time",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::Day {
+                        day: 3,
+                        part: Some(1),
+                    },
+                    input: Some(Input::File(PathBuf::from("other.txt"))),
+                    time: true,
+                    trace: false,
+                    format: Format::Text,
+                    jobs: default_jobs(),
+                })),
+            ),
+            (
+                "run --input - 7",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::Day { day: 7, part: None },
+                    input: Some(Input::Stdin),
+                    time: false,
+                    trace: false,
+                    format: Format::Text,
+                    jobs: default_jobs(),
+                })),
+            ),
+            (
+                "run --all --format json",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::All,
+                    input: None,
+                    time: false,
+                    trace: false,
+                    format: Format::Json,
+                    jobs: default_jobs(),
+                })),
+            ),
+            (
+                "run --all -j 4",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::All,
+                    input: None,
+                    time: false,
+                    trace: false,
+                    format: Format::Text,
+                    jobs: 4,
+                })),
+            ),
+            (
+                "run 19 --trace",
+                Ok(Command::Run(RunArgs {
+                    year: 2021,
+                    target: Target::Day {
+                        day: 19,
+                        part: None,
+                    },
+                    input: None,
+                    time: false,
+                    trace: true,
+                    format: Format::Text,
+                    jobs: default_jobs(),
+                })),
+            ),
+            (
+                "bench",
+                Ok(Command::Bench(BenchArgs {
+                    run: RunArgs {
+                        year: 2021,
+                        target: Target::All,
+                        input: None,
+                        time: true,
+                        trace: false,
+                        format: Format::Text,
+                        jobs: 1,
+                    },
+                    runs: 10,
+                })),
+            ),
+            (
+                "bench 19 --runs 3",
+                Ok(Command::Bench(BenchArgs {
+                    run: RunArgs {
+                        year: 2021,
+                        target: Target::Day {
+                            day: 19,
+                            part: None,
+                        },
+                        input: None,
+                        time: true,
+                        trace: false,
+                        format: Format::Text,
+                        jobs: 1,
+                    },
+                    runs: 3,
+                })),
+            ),
+            (
+                "verify",
+                Ok(Command::Verify(VerifyArgs {
+                    year: 2021,
+                    target: Target::All,
+                    record: false,
+                })),
+            ),
+            (
+                "verify 4 --record -y 2020",
+                Ok(Command::Verify(VerifyArgs {
+                    year: 2020,
+                    target: Target::Day { day: 4, part: None },
+                    record: true,
+                })),
+            ),
+            (
+                "submit 3 2",
+                Ok(Command::Submit(SubmitArgs {
+                    year: 2021,
+                    day: 3,
+                    part: 2,
+                    input: None,
+                    answer: None,
+                })),
+            ),
+            (
+                "submit 3 1 --answer 198",
+                Ok(Command::Submit(SubmitArgs {
+                    year: 2021,
+                    day: 3,
+                    part: 1,
+                    input: None,
+                    answer: Some("198".to_string()),
+                })),
+            ),
+            ("submit 3", Err(CliError::MissingPart)),
+            (
+                "submit 3 1 --input x.txt --answer 198",
+                Err(CliError::UnexpectedArgument("--input".to_string())),
+            ),
+            (
+                "run 3 1 --answer 198",
+                Err(CliError::UnexpectedArgument("--answer".to_string())),
+            ),
+            (
+                "watch 16 2",
+                Ok(Command::Watch(WatchArgs {
+                    year: 2021,
+                    day: 16,
+                    part: Some(2),
+                    input: None,
+                })),
+            ),
+            (
+                "watch 13 --input example.txt",
+                Ok(Command::Watch(WatchArgs {
+                    year: 2021,
+                    day: 13,
+                    part: None,
+                    input: Some(PathBuf::from("example.txt")),
+                })),
+            ),
+            ("watch", Err(CliError::MissingDay)),
+            ("watch 13 --input -", Err(CliError::WatchStdin)),
+            (
+                "watch 13 --time",
+                Err(CliError::UnexpectedArgument("--time".to_string())),
+            ),
+            (
+                "new 26 --year 2022",
+                Ok(Command::New {
+                    year: 2022,
+                    day: 26,
+                }),
+            ),
+            ("new", Err(CliError::MissingDay)),
+            ("migrate", Ok(Command::Migrate { year: 2021 })),
+            (
+                "migrate 3",
+                Err(CliError::UnexpectedArgument("3".to_string())),
+            ),
+            (
+                "new 3 --time",
+                Err(CliError::UnexpectedArgument("--time".to_string())),
+            ),
+            ("--help", Ok(Command::Help)),
+            ("", Err(CliError::MissingCommand)),
+            ("jump 1", Err(CliError::UnknownCommand("jump".to_string()))),
+            ("run", Err(CliError::MissingDay)),
+            ("run x", Err(CliError::InvalidNumber("x".to_string()))),
+            (
+                "run 1 --year twenty",
+                Err(CliError::InvalidNumber("twenty".to_string())),
+            ),
+            (
+                "run 1 --input",
+                Err(CliError::MissingValue("--input".to_string())),
+            ),
+            ("run --all --input -", Err(CliError::InputWithAll)),
+            (
+                "run --all 3",
+                Err(CliError::UnexpectedArgument("3".to_string())),
+            ),
+            (
+                "run 1 2 3",
+                Err(CliError::UnexpectedArgument("3".to_string())),
+            ),
+            (
+                "run 1 --runs 2",
+                Err(CliError::UnexpectedArgument("--runs".to_string())),
+            ),
+            (
+                "run 1 --format xml",
+                Err(CliError::InvalidFormat("xml".to_string())),
+            ),
+            (
+                "bench --format csv",
+                Err(CliError::UnexpectedArgument("--format".to_string())),
+            ),
+            ("run --all --jobs 0", Err(CliError::NoJobs)),
+            (
+                "bench --jobs 2",
+                Err(CliError::UnexpectedArgument("--jobs".to_string())),
+            ),
+            (
+                "bench 19 --trace",
+                Err(CliError::UnexpectedArgument("--trace".to_string())),
+            ),
+            (
+                "run 1 --record",
+                Err(CliError::UnexpectedArgument("--record".to_string())),
+            ),
+            (
+                "verify 1 --input x.txt",
+                Err(CliError::UnexpectedArgument("--input".to_string())),
+            ),
+            (
+                "run 1 --fast",
+                Err(CliError::UnexpectedArgument("--fast".to_string())),
+            ),
+        ];
+
+        for (args, expected) in test_cases {
+            assert_eq!(parse(args), expected, "args: {}", args);
+        }
+    }
+}
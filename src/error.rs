@@ -0,0 +1,152 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error returned by solutions, parsing errors carry the position
+/// of the malformed input when it is known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    Io(String),
+    /// Downloading an input failed.
+    Fetch(String),
+    /// Submitting an answer failed or was refused.
+    Submit(String),
+    Parse {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    NoSolution(String),
+    UnknownPart(u16),
+    /// A value cannot be represented in the target format.
+    Encode(String),
+}
+
+impl Error {
+    pub fn parse<M: Into<String>>(message: M) -> Error {
+        Error::Parse {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn unexpected_char(c: char) -> Error {
+        Error::parse(format!("unexpected character: {:?}", c))
+    }
+
+    pub fn no_solution<M: Into<String>>(message: M) -> Error {
+        Error::NoSolution(message.into())
+    }
+
+    /// Sets the line (1-based) of parsing error unless it is already known.
+    pub fn at_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse {
+                message,
+                line: None,
+                column,
+            } => Error::Parse {
+                message,
+                line: Some(line),
+                column,
+            },
+            other => other,
+        }
+    }
+
+    /// Sets the column (1-based) of parsing error unless it is already known.
+    pub fn at_column(self, column: usize) -> Error {
+        match self {
+            Error::Parse {
+                message,
+                line,
+                column: None,
+            } => Error::Parse {
+                message,
+                line,
+                column: Some(column),
+            },
+            other => other,
+        }
+    }
+
+    pub fn at(self, line: usize, column: usize) -> Error {
+        self.at_line(line).at_column(column)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(message) => write!(f, "io error: {}", message),
+            Error::Fetch(message) => write!(f, "failed to fetch input: {}", message),
+            Error::Submit(message) => write!(f, "failed to submit answer: {}", message),
+            Error::Parse {
+                message,
+                line,
+                column,
+            } => {
+                match (line, column) {
+                    (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+                    (Some(line), None) => write!(f, "line {}: ", line)?,
+                    (None, Some(column)) => write!(f, "column {}: ", column)?,
+                    (None, None) => {}
+                }
+                write!(f, "{}", message)
+            }
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::UnknownPart(part) => write!(f, "unknown part: {}", part),
+            Error::Encode(message) => write!(f, "failed to encode: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err.to_string())
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::parse(format!("invalid number: {}", err))
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+
+    #[test]
+    fn test() {
+        let err = Error::unexpected_char('x').at(3, 7);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 7: unexpected character: 'x'"
+        );
+
+        // Position closest to the source of the error is kept.
+        let err = Error::parse("missing value").at_line(2).at(5, 1);
+        assert_eq!(err.to_string(), "line 2, column 1: missing value");
+
+        let err = Error::from("abc".parse::<u32>().unwrap_err());
+        assert_eq!(
+            err.to_string(),
+            "invalid number: invalid digit found in string"
+        );
+
+        let err = Error::no_solution("end is unreachable").at_line(1);
+        assert_eq!(err.to_string(), "no solution: end is unreachable");
+    }
+}
@@ -0,0 +1,92 @@
+//! HTTP client talking to the puzzle server, over TLS for `https://` URLs.
+
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+const USER_AGENT: &str = "advent-of-code-2021";
+
+/// Fetches `url` and returns the body of a successful response, errors are
+/// described along with the URL.
+pub fn get(url: &str, session: Option<&str>) -> Result<String, String> {
+    send(url, request("GET", url, session)?.call())
+}
+
+/// Posts `form`, which must be already URL-encoded, to `url`.
+pub fn post_form(url: &str, session: Option<&str>, form: &str) -> Result<String, String> {
+    let request =
+        request("POST", url, session)?.set("Content-Type", "application/x-www-form-urlencoded");
+    send(url, request.send_string(form))
+}
+
+/// Encodes `value` to be used in a form field.
+pub fn encode_form_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn request(method: &str, url: &str, session: Option<&str>) -> Result<ureq::Request, String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!(
+            "{}: only http:// and https:// URLs are supported",
+            url
+        ));
+    }
+    let agent = ureq::AgentBuilder::new()
+        .timeout(TIMEOUT)
+        .user_agent(USER_AGENT)
+        // Redirects of the puzzle server lead to the login page instead of the content.
+        .redirects(0)
+        .build();
+    let request = agent.request(method, url);
+    Ok(match session {
+        Some(session) => request.set("Cookie", &format!("session={}", session)),
+        None => request,
+    })
+}
+
+fn send(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    let error = |message: String| format!("{}: {}", url, message);
+
+    let response = match response {
+        Ok(response) if response.status() == 200 => response,
+        Ok(response) | Err(ureq::Error::Status(_, response)) => {
+            return Err(error(format!(
+                "unexpected response: {} {} {}",
+                response.http_version(),
+                response.status(),
+                response.status_text()
+            )))
+        }
+        Err(ureq::Error::Transport(transport)) => return Err(error(transport.to_string())),
+    };
+    response
+        .into_string()
+        .map_err(|e| error(format!("failed to read the response: {}", e)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::{encode_form_value, get};
+
+    #[test]
+    fn test() {
+        assert_eq!(
+            get("ftp://example.com/input", None),
+            Err(
+                "ftp://example.com/input: only http:// and https:// URLs are supported".to_string()
+            )
+        );
+
+        assert_eq!(encode_form_value("123"), "123");
+        assert_eq!(encode_form_value("a b&c=d"), "a+b%26c%3Dd");
+        assert_eq!(encode_form_value("é"), "%C3%A9");
+    }
+}
@@ -0,0 +1,276 @@
+use crate::error::{Error, Result};
+use crate::http;
+use crate::solution::DEFAULT_YEAR;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Environment variables configuring [`Inputs::from_env`].
+pub const DIR_VAR: &str = "AOC_INPUTS_DIR";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Puzzle inputs cached as `<dir>/<year>/<day>.txt`, missing ones are downloaded from
+/// `<base_url>/<year>/day/<day>/input` when a base URL is configured.
+///
+/// Inputs are returned without trailing newlines, whatever the file ends with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inputs {
+    dir: PathBuf,
+    remote: Option<Remote>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Remote {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Remote {
+    fn input_url(&self, year: u16, day: u16) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+}
+
+impl Inputs {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Inputs {
+        Inputs {
+            dir: dir.into(),
+            remote: None,
+        }
+    }
+
+    /// Reads the configuration from `AOC_INPUTS_DIR`, `AOC_BASE_URL` and `AOC_SESSION`.
+    pub fn from_env() -> Inputs {
+        let var = |name| {
+            env::var(name)
+                .ok()
+                .filter(|value: &String| !value.is_empty())
+        };
+
+        let inputs = Inputs::new(var(DIR_VAR).unwrap_or_else(|| DEFAULT_DIR.to_string()));
+        match var(BASE_URL_VAR) {
+            Some(base_url) => inputs.with_remote(base_url, var(SESSION_VAR)),
+            None => inputs,
+        }
+    }
+
+    /// Enables downloading of missing inputs, `session` is sent as the session cookie.
+    pub fn with_remote<S: Into<String>>(self, base_url: S, session: Option<String>) -> Inputs {
+        Inputs {
+            remote: Some(Remote {
+                base_url: base_url.into().trim_end_matches('/').to_string(),
+                session,
+            }),
+            ..self
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u16) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{}.txt", day))
+    }
+
+    /// Tells whether the input is cached or can be downloaded.
+    pub fn is_available(&self, year: u16, day: u16) -> bool {
+        self.remote.is_some()
+            || self.path(year, day).exists()
+            || self.legacy_path(year, day).is_some()
+    }
+
+    /// Path the input is read from, [`Inputs::path`] unless only the legacy location
+    /// has it.
+    pub fn resolve(&self, year: u16, day: u16) -> PathBuf {
+        let path = self.path(year, day);
+        if path.exists() {
+            return path;
+        }
+        self.legacy_path(year, day).unwrap_or(path)
+    }
+
+    /// Reads the input, falling back to the legacy location and then to downloading it.
+    pub fn load(&self, year: u16, day: u16) -> Result<String> {
+        let path = self.resolve(year, day);
+        if !path.exists() {
+            self.fetch(year, day)?;
+        }
+
+        let input = fs::read_to_string(&path)
+            .map_err(|e| Error::Io(format!("failed to read {}: {}", path.display(), e)))?;
+        Ok(normalize(&input).to_string())
+    }
+
+    // Inputs of the default year used to be stored as `<day>.txt` and before that
+    // as `<day>.1.txt`, directly in the inputs directory.
+    fn legacy_path(&self, year: u16, day: u16) -> Option<PathBuf> {
+        if year != DEFAULT_YEAR {
+            return None;
+        }
+        [format!("{}.txt", day), format!("{}.1.txt", day)]
+            .iter()
+            .map(|name| self.dir.join(name))
+            .find(|path| path.exists())
+    }
+
+    /// Moves the input stored at its legacy location to [`Inputs::path`], unless
+    /// there is one already. Returns the legacy path it was moved from.
+    pub fn migrate(&self, year: u16, day: u16) -> Result<Option<PathBuf>> {
+        let path = self.path(year, day);
+        let legacy = match self.legacy_path(year, day) {
+            Some(legacy) if !path.exists() => legacy,
+            _ => return Ok(None),
+        };
+        fs::create_dir_all(self.dir.join(year.to_string()))
+            .and_then(|_| fs::rename(&legacy, &path))
+            .map_err(|e| Error::Io(format!("failed to rename {}: {}", legacy.display(), e)))?;
+        Ok(Some(legacy))
+    }
+
+    fn fetch(&self, year: u16, day: u16) -> Result<()> {
+        let path = self.path(year, day);
+        let remote = self
+            .remote
+            .as_ref()
+            .ok_or_else(|| Error::Io(format!("input file not found: {}", path.display())))?;
+
+        let url = remote.input_url(year, day);
+        let input = http::get(&url, remote.session.as_deref()).map_err(Error::Fetch)?;
+
+        // Written under a temporary name first, so that a partial download is never cached.
+        let dir = self.dir.join(year.to_string());
+        let partial = dir.join(format!("{}.txt.part", day));
+        fs::create_dir_all(&dir)
+            .and_then(|_| fs::write(&partial, format!("{}\n", normalize(&input))))
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| Error::Io(format!("failed to store {}: {}", path.display(), e)))
+    }
+}
+
+/// Strips trailing newlines the same way inputs are returned by [`Inputs::load`].
+pub fn normalize(input: &str) -> &str {
+    input.trim_end_matches(['\n', '\r'])
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::inputs::Inputs;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use tempfile::TempDir;
+
+    /// Serves `/<year>/day/<N>/input` for days below 10 to requests with the right session.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let head: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let path = head[0].split_whitespace().nth(1).unwrap();
+                let day = path
+                    .strip_suffix("/input")
+                    .and_then(|p| p.split_once("/day/"))
+                    .and_then(|(year, day)| {
+                        Some((year.strip_prefix('/')?, day.parse::<u16>().ok()?))
+                    });
+
+                let response = match day {
+                    _ if !head.contains(&"Cookie: session=secret".to_string()) => {
+                        "HTTP/1.0 400 Bad Request\r\n\r\n".to_string()
+                    }
+                    Some((year, day)) if day < 10 => {
+                        format!("HTTP/1.0 200 OK\r\n\r\ninput of {} {}\r\n\n", year, day)
+                    }
+                    _ => "HTTP/1.0 404 Not Found\r\n\r\n".to_string(),
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().to_path_buf();
+
+        // Inputs of 2021 used to be stored directly in the inputs directory.
+        fs::write(dir.join("1.txt"), "cached\n\n").unwrap();
+        fs::write(dir.join("2.1.txt"), "legacy\r\n").unwrap();
+
+        let local = Inputs::new(&dir);
+        assert!(!local.is_available(2022, 1));
+        assert_eq!(local.resolve(2021, 1), dir.join("1.txt"));
+        assert_eq!(local.resolve(2021, 2), dir.join("2.1.txt"));
+        assert_eq!(local.resolve(2021, 3), dir.join("2021/3.txt"));
+        assert_eq!(local.resolve(2022, 1), dir.join("2022/1.txt"));
+        assert_eq!(local.load(2021, 1), Ok("cached".to_string()));
+        assert_eq!(local.load(2021, 2), Ok("legacy".to_string()));
+        // Reading leaves them where they are, only migrating moves them.
+        assert!(dir.join("1.txt").exists() && dir.join("2.1.txt").exists());
+        assert_eq!(local.migrate(2021, 1), Ok(Some(dir.join("1.txt"))));
+        assert_eq!(local.migrate(2021, 2), Ok(Some(dir.join("2.1.txt"))));
+        assert_eq!(local.migrate(2021, 2), Ok(None));
+        assert!(dir.join("2021/1.txt").exists() && !dir.join("1.txt").exists());
+        assert!(dir.join("2021/2.txt").exists() && !dir.join("2.1.txt").exists());
+        assert_eq!(local.resolve(2021, 2), dir.join("2021/2.txt"));
+        assert_eq!(local.load(2021, 2), Ok("legacy".to_string()));
+        assert!(!local.is_available(2021, 3));
+        assert!(matches!(local.load(2021, 3), Err(Error::Io(_))));
+
+        let (base_url, requests) = serve();
+        let remote = Inputs::new(&dir).with_remote(base_url.clone(), Some("secret".to_string()));
+        assert_eq!(remote.load(2021, 1), Ok("cached".to_string()));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+        assert_eq!(remote.load(2021, 3), Ok("input of 2021 3".to_string()));
+        assert_eq!(remote.load(2021, 3), Ok("input of 2021 3".to_string()));
+        assert_eq!(remote.load(2022, 3), Ok("input of 2022 3".to_string()));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("2021/3.txt")).unwrap(),
+            "input of 2021 3\n"
+        );
+
+        let err = remote.load(2021, 12).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "failed to fetch input: {}/2021/day/12/input: unexpected response: HTTP/1.0 404 Not Found",
+                base_url
+            )
+        );
+        assert!(!dir.join("2021/12.txt").exists());
+
+        let anonymous = Inputs::new(&dir).with_remote(base_url, None);
+        assert!(anonymous.load(2021, 4).is_err());
+
+        let site = Inputs::new(&dir).with_remote("https://adventofcode.com/", None);
+        assert_eq!(
+            site.remote.unwrap().input_url(2021, 5),
+            "https://adventofcode.com/2021/day/5/input"
+        );
+        let ftp = Inputs::new(&dir).with_remote("ftp://adventofcode.com", None);
+        assert_eq!(
+            ftp.load(2021, 5).unwrap_err().to_string(),
+            "failed to fetch input: ftp://adventofcode.com/2021/day/5/input: \
+             only http:// and https:// URLs are supported"
+        );
+    }
+}
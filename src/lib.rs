@@ -0,0 +1,34 @@
+//! Solutions of [Advent of Code](https://adventofcode.com) events.
+//!
+//! Days are grouped by year, e.g. `y2021::day16`, and share helpers such as [`util`].
+//! Every day is available as a module exposing `part_1` and `part_2` functions,
+//! which take the puzzle input as a string and return the answer:
+//!
+//! ```
+//! use advent_of_code_2021::y2021::day06;
+//!
+//! assert_eq!(day06::part_1("3,4,3,1,2"), Ok(5934));
+//! ```
+//!
+//! Malformed input results in an [`error::Error`] pointing at the offending
+//! line and column instead of a panic:
+//!
+//! ```
+//! use advent_of_code_2021::y2021::day06;
+//!
+//! let err = day06::part_1("3,4,x").unwrap_err();
+//! assert_eq!(err.to_string(), "line 1, column 5: invalid number: invalid digit found in string");
+//! ```
+//!
+//! All days are also registered in [`solution::EVENTS`], which allows to
+//! run them without knowing the types of their answers.
+
+pub mod error;
+mod http;
+pub mod inputs;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod trace;
+pub mod util;
+pub mod y2021;
@@ -1,354 +1,443 @@
-use std::fmt::Display;
-use std::path::Path;
-use std::{env, process};
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod util;
-
-pub fn run_task<T, O, P>(func: T, day: u16, task: u16, input_file: P)
-where
-    T: Fn(P) -> O,
-    O: Display,
-    P: AsRef<Path>,
-{
-    println!("Running Day {} task {}", day, task);
-
-    let out = func(input_file);
-
-    println!("Output: {}", out);
+use crate::cli::{Command, Input, RunArgs, SubmitArgs, Target, VerifyArgs, WatchArgs};
+use crate::output::{Format, RunResult};
+use advent_of_code_2021::error::Result;
+use advent_of_code_2021::inputs::Inputs;
+use advent_of_code_2021::solution::{find_event, Event, Solution, PARTS};
+use advent_of_code_2021::submit::{Submitter, Verdict};
+use advent_of_code_2021::trace;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use std::{env, fs, process};
+
+mod bench;
+mod cli;
+mod memory;
+mod output;
+mod pool;
+mod scaffold;
+mod verify;
+mod watch;
+
+/// Runs a single part and describes the result as printed lines.
+pub fn run_task(solution: &dyn Solution, part: u16, input: &str, time: bool) -> Result<String> {
+    let mut report = format!("Running Day {} task {}\n", solution.day(), part);
+
+    if time {
+        let timing = bench::measure(solution, part, input, 1)?;
+        report.push_str(&format!("Output: {}\n", timing.answer));
+        report.push_str(&format!(
+            "Time: parse {}, solve {}",
+            bench::format_duration(timing.parse.median),
+            bench::format_duration(timing.solve.median)
+        ));
+        if let Some(usage) = timing.memory {
+            report.push_str(&format!("\nMemory: {}", usage));
+        }
+        return Ok(report);
+    }
+
+    let out = solution.run_part(part, input)?;
+
+    report.push_str(&format!("Output: {}", out));
+    Ok(report)
+}
+
+/// A part scheduled to run together with its input.
+struct Job {
+    solution: &'static dyn Solution,
+    part: u16,
+    input_path: PathBuf,
+    input: String,
+}
+
+enum Outcome {
+    Line(String),
+    Result(RunResult),
 }
 
 fn main() {
-    println!("Running Advent of code 2021!");
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("Invalid arguments: {}\n\n{}", err, cli::USAGE);
+            process::exit(1);
+        }
+    };
 
-    let args: Vec<String> = env::args().collect();
+    let result = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Command::Run(args) => run(args, None),
+        Command::Bench(args) => run(args.run, Some(args.runs)),
+        Command::Verify(args) => verify(args),
+        Command::Submit(args) => submit(args),
+        Command::Watch(args) => watch(args),
+        Command::New { year, day } => scaffold::new_day(Path::new("."), year, day).map(|changed| {
+            for path in changed {
+                println!("Updated {}", path.display());
+            }
+        }),
+        Command::Migrate { year } => migrate(year),
+    };
 
-    if args.len() < 3 {
-        println!("Invalid arguments. Provide [DAY] [TASK] as an arguments.");
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
         process::exit(1);
     }
+}
 
-    let args: [&str; 2] = [&args[1], &args[2]];
-
-    match args {
-        ["1", "1"] => run_task(day01::day_1_1, 1, 1, "inputs/1.1.txt"),
-        ["1", "2"] => run_task(day01::day_1_2, 1, 2, "inputs/1.1.txt"),
-        ["2", "1"] => run_task(day02::day_2_1, 2, 1, "inputs/2.1.txt"),
-        ["2", "2"] => run_task(day02::day_2_2, 2, 2, "inputs/2.1.txt"),
-        ["3", "1"] => run_task(day03::day_3_1, 3, 1, "inputs/3.1.txt"),
-        ["3", "2"] => run_task(day03::day_3_2, 3, 2, "inputs/3.1.txt"),
-        ["4", "1"] => run_task(day04::day_4_1, 4, 1, "inputs/4.1.txt"),
-        ["4", "2"] => run_task(day04::day_4_2, 4, 2, "inputs/4.1.txt"),
-        ["5", "1"] => run_task(day05::day_5_1, 5, 1, "inputs/5.1.txt"),
-        ["5", "2"] => run_task(day05::day_5_2, 5, 2, "inputs/5.1.txt"),
-        ["6", "1"] => run_task(day06::day_6_1, 6, 1, "inputs/6.1.txt"),
-        ["6", "2"] => run_task(day06::day_6_2, 6, 2, "inputs/6.1.txt"),
-        ["7", "1"] => run_task(day07::day_7_1, 7, 1, "inputs/7.1.txt"),
-        ["7", "2"] => run_task(day07::day_7_2, 7, 2, "inputs/7.1.txt"),
-        ["8", "1"] => run_task(day08::day_8_1, 8, 1, "inputs/8.txt"),
-        ["8", "2"] => run_task(day08::day_8_2, 8, 2, "inputs/8.txt"),
-        ["9", "1"] => run_task(day09::day_9_1, 9, 1, "inputs/9.txt"),
-        ["9", "2"] => run_task(day09::day_9_2, 9, 2, "inputs/9.txt"),
-        ["10", "1"] => run_task(day10::day_10_1, 10, 1, "inputs/10.txt"),
-        ["10", "2"] => run_task(day10::day_10_2, 10, 2, "inputs/10.txt"),
-        ["11", "1"] => run_task(day11::day_11_1, 11, 1, "inputs/11.txt"),
-        ["11", "2"] => run_task(day11::day_11_2, 11, 2, "inputs/11.txt"),
-        ["12", "1"] => run_task(day12::day_12_1, 12, 1, "inputs/12.txt"),
-        ["12", "2"] => run_task(day12::day_12_2, 12, 2, "inputs/12.txt"),
-        ["13", "1"] => run_task(day13::day_13_1, 13, 1, "inputs/13.txt"),
-        ["13", "2"] => run_task(day13::day_13_2, 13, 2, "inputs/13.txt"),
-        ["14", "1"] => run_task(day14::day_14_1, 14, 1, "inputs/14.txt"),
-        ["14", "2"] => run_task(day14::day_14_2, 14, 2, "inputs/14.txt"),
-        ["15", "1"] => run_task(day15::day_15_1, 15, 1, "inputs/15.txt"),
-        ["15", "2"] => run_task(day15::day_15_2, 15, 2, "inputs/15.txt"),
-        ["16", "1"] => run_task(day16::day_16_1, 16, 1, "inputs/16.txt"),
-        ["16", "2"] => run_task(day16::day_16_2, 16, 2, "inputs/16.txt"),
-        ["17", "1"] => run_task(day17::day_17_1, 17, 1, "inputs/17.txt"),
-        ["17", "2"] => run_task(day17::day_17_2, 17, 2, "inputs/17.txt"),
-        ["18", "1"] => run_task(day18::day_18_1, 18, 1, "inputs/18.txt"),
-        ["18", "2"] => run_task(day18::day_18_2, 18, 2, "inputs/18.txt"),
-        ["19", "1"] => run_task(day19::day_19_1, 19, 1, "inputs/19.txt"),
-        ["19", "2"] => run_task(day19::day_19_2, 19, 2, "inputs/19.txt"),
-        ["20", "1"] => run_task(day20::day_20_1, 20, 1, "inputs/20.txt"),
-        ["20", "2"] => run_task(day20::day_20_2, 20, 2, "inputs/20.txt"),
-        ["21", "1"] => run_task(day21::day_21_1, 21, 1, "inputs/21.txt"),
-        ["21", "2"] => run_task(day21::day_21_2, 21, 2, "inputs/21.txt"),
-        ["22", "1"] => run_task(day22::day_22_1, 22, 1, "inputs/22.txt"),
-        ["22", "2"] => run_task(day22::day_22_2, 22, 2, "inputs/22.txt"),
-        ["23", "1"] => run_task(day23::day_23_1, 23, 1, "inputs/23.txt"),
-        ["23", "2"] => run_task(day23::day_23_2, 23, 2, "inputs/23.txt"),
-        ["24", "1"] => run_task(day24::day_24_1, 24, 1, "inputs/24.txt"),
-        ["24", "2"] => run_task(day24::day_24_2, 24, 2, "inputs/24.txt"),
-        ["25", "1"] => run_task(day25::day_25_1, 25, 1, "inputs/25.txt"),
-        ["25", "2"] => run_task(day25::day_25_2, 25, 2, "inputs/25.txt"),
-        [day, task] => {
-            println!("Invalid arguments, day: {}, task: {}", day, task);
-            process::exit(1)
-        }
+/// Runs selected tasks, when `bench_runs` is set each task is measured instead
+/// and printed as a row of the summary table.
+fn run(args: RunArgs, bench_runs: Option<usize>) -> std::result::Result<(), String> {
+    let event = select_event(args.year)?;
+    let tasks = select_tasks(event, &args.target)?;
+    let inputs = Inputs::from_env();
+    if args.trace {
+        trace::enable();
+    }
+
+    let stdin_input = match &args.input {
+        Some(Input::Stdin) => Some(read_stdin()?),
+        _ => None,
+    };
+
+    match (bench_runs, args.format) {
+        (Some(_), _) => println!("{}", bench::table_header()),
+        (None, Format::Text) => println!("Running Advent of code {}!", event.year),
+        _ => {}
     }
+
+    let mut jobs = vec![];
+    for (solution, part) in tasks {
+        let (input_path, input) = match (&args.input, &stdin_input) {
+            (_, Some(input)) => (PathBuf::from("-"), input.clone()),
+            (Some(Input::File(path)), _) => (path.clone(), read_input_file(path)?),
+            // When running everything, days without local input are skipped.
+            _ => match load_input(&inputs, event, solution, part, args.target == Target::All)? {
+                Some(loaded) => loaded,
+                None => continue,
+            },
+        };
+        jobs.push(Job {
+            solution,
+            part,
+            input_path,
+            input,
+        });
+    }
+
+    let work = |job: &Job| {
+        let Job { solution, part, .. } = *job;
+        let input = job.input.as_str();
+        let _span = trace::span(format!("day {} part {}", solution.day(), part));
+        let outcome = match (bench_runs, args.format) {
+            (Some(runs), _) => bench::measure(solution, part, input, runs)
+                .map(|timing| Outcome::Line(bench::table_row(solution.day(), part, &timing))),
+            (None, Format::Text) => run_task(solution, part, input, args.time).map(Outcome::Line),
+            (None, _) => {
+                let start = Instant::now();
+                solution.run_part(part, input).map(|answer| {
+                    Outcome::Result(RunResult {
+                        day: solution.day(),
+                        part,
+                        answer,
+                        input: job.input_path.clone(),
+                        elapsed: start.elapsed(),
+                    })
+                })
+            }
+        };
+        outcome.map_err(|err| format!("day {} task {} failed: {}", solution.day(), part, err))
+    };
+
+    // Slow parts are started first so they do not hold up the end of the run.
+    let mut schedule: Vec<usize> = (0..jobs.len()).collect();
+    schedule.sort_by_key(|&i| !jobs[i].solution.is_slow());
+
+    let mut results = vec![];
+    pool::run_ordered(&jobs, &schedule, args.jobs, work, |outcome| {
+        outcome.map(|outcome| match outcome {
+            Outcome::Line(line) => println!("{}", line),
+            Outcome::Result(result) => results.push(result),
+        })
+    })?;
+    print!("{}", output::render(args.format, &results));
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod test {
-    use crate::day01::{day_1_1, day_1_2};
-    use crate::day02::{day_2_1, day_2_2};
-    use crate::day03::{day_3_1, day_3_2};
-    use crate::day04::{day_4_1, day_4_2};
-    use crate::day05::{day_5_1, day_5_2};
-    use crate::day06::{day_6_1, day_6_2};
-    use crate::day07::{day_7_1, day_7_2};
-    use crate::day08::{day_8_1, day_8_2};
-    use crate::day09::{day_9_1, day_9_2};
-    use crate::day10::{day_10_1, day_10_2};
-    use crate::day11::{day_11_1, day_11_2};
-    use crate::day12::{day_12_1, day_12_2};
-    use crate::day13::{day_13_1, day_13_2};
-    use crate::day14::{day_14_1, day_14_2};
-    use crate::day15::{day_15_1, day_15_2};
-    use crate::day16::{day_16_1, day_16_2};
-    use crate::day17::{day_17_1, day_17_2};
-    use crate::day18::{day_18_1, day_18_2};
-    use crate::day19::{day_19_1, day_19_2};
-    use crate::day20::{day_20_1, day_20_2};
-    use crate::day21::{day_21_1, day_21_2};
-    use crate::day22::{day_22_1, day_22_2};
-    use crate::day23::{day_23_1, day_23_2};
-    use crate::day24::{day_24_1, day_24_2};
-    use crate::day25::day_25_1;
-    use std::fs;
-    use std::path::Path;
-    use std::str::FromStr;
+/// Loads the input of the part along with the path it was read from. When `skip_missing`
+/// is set, a missing input is reported and gives `None` instead of an error.
+fn load_input(
+    inputs: &Inputs,
+    event: &Event,
+    solution: &dyn Solution,
+    part: u16,
+    skip_missing: bool,
+) -> std::result::Result<Option<(PathBuf, String)>, String> {
+    let path = inputs.resolve(event.year, solution.day());
+    if skip_missing && !inputs.is_available(event.year, solution.day()) {
+        eprintln!(
+            "Skipping day {} task {}, input file not found: {}",
+            solution.day(),
+            part,
+            path.display()
+        );
+        return Ok(None);
+    }
+    let input = inputs
+        .load(event.year, solution.day())
+        .map_err(|err| err.to_string())?;
+    // Downloading stores the input at the path resolved before.
+    Ok(Some((path, input)))
+}
+
+/// Checks answers of selected tasks against the expected ones and prints
+/// the results as a day by part matrix.
+fn verify(args: VerifyArgs) -> std::result::Result<(), String> {
+    let event = select_event(args.year)?;
+    let tasks = select_tasks(event, &args.target)?;
+
+    let inputs = Inputs::from_env();
 
-    fn read_output<P: AsRef<Path>, T: FromStr>(out_file: P) -> T
-    where
-        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    println!("{}", verify::matrix_header(&PARTS));
+
+    let mut statuses = vec![];
+    let mut details = vec![];
+    let mut row: Vec<Option<verify::Status>> = vec![None; PARTS.len()];
+    for (i, &(solution, part)) in tasks.iter().enumerate() {
+        let status = verify::verify_part(event, solution, part, &inputs, args.record);
+        details.extend(verify::details(solution.day(), part, &status));
+        statuses.push(status.clone());
+
+        let col = PARTS.iter().position(|&p| p == part).unwrap_or_default();
+        row[col] = Some(status);
+
+        // Tasks are ordered by day, so the row is complete once the day changes.
+        let next_day = tasks.get(i + 1).map(|(next, _)| next.day());
+        if next_day != Some(solution.day()) {
+            println!("{}", verify::matrix_row(solution.day(), &row));
+            row = vec![None; PARTS.len()];
+        }
+    }
+
+    if !details.is_empty() {
+        println!("\n{}", details.join("\n"));
+    }
+    println!("\n{}", verify::summary(&statuses));
+
+    let failed = statuses.iter().filter(|s| s.is_failure()).count();
+    if failed > 0 {
+        return Err(format!("{} parts failed verification", failed));
+    }
+    Ok(())
+}
+
+/// Submits the answer of a single part, the accepted answer is stored as
+/// the expected one unless there already is one.
+fn submit(args: SubmitArgs) -> std::result::Result<(), String> {
+    let event = select_event(args.year)?;
+    let (solution, part) = match select_tasks(
+        event,
+        &Target::Day {
+            day: args.day,
+            part: Some(args.part),
+        },
+    )?[..]
     {
-        fs::read_to_string(out_file)
-            .expect("failed to read out file")
-            .parse()
-            .expect("failed to parse output")
+        [task] => task,
+        _ => return Err(format!("unknown part: {}", args.part)),
+    };
+    let submitter = Submitter::from_env(event.year).map_err(|err| err.to_string())?;
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let input = match &args.input {
+                Some(Input::Stdin) => read_stdin()?,
+                Some(Input::File(path)) => read_input_file(path)?,
+                None => Inputs::from_env()
+                    .load(event.year, solution.day())
+                    .map_err(|err| err.to_string())?,
+            };
+            solution
+                .run_part(part, &input)
+                .map_err(|err| format!("day {} task {} failed: {}", solution.day(), part, err))?
+        }
+    };
+
+    let attempt = submitter
+        .submit(solution.day(), part, &answer)
+        .map_err(|err| err.to_string())?;
+    println!(
+        "Day {} task {}: {} is {}\n{}",
+        attempt.day, attempt.part, attempt.answer, attempt.verdict, attempt.response
+    );
+    if attempt.verdict != Verdict::Correct {
+        return Err(format!("answer was not accepted: {}", attempt.verdict));
+    }
+
+    let output_path = verify::output_path(event, solution.day(), part);
+    if !output_path.exists() {
+        output_path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&output_path, format!("{}\n", attempt.answer)))
+            .map_err(|e| format!("failed to record {}: {}", output_path.display(), e))?;
     }
+    Ok(())
+}
 
-    fn in_path(file: &str) -> String {
-        format!("inputs/{}", file)
+/// Re-solves the selected parts of a day as its input or the sources change.
+fn watch(args: WatchArgs) -> std::result::Result<(), String> {
+    let event = select_event(args.year)?;
+    let target = Target::Day {
+        day: args.day,
+        part: args.part,
+    };
+    // Validates the part as well.
+    let (solution, _) = select_tasks(event, &target)?[0];
+    watch::watch(event, solution, &args)
+}
+
+/// Moves inputs and expected answers of the year out of their legacy location, which
+/// they are only read from.
+fn migrate(year: u16) -> std::result::Result<(), String> {
+    let event = select_event(year)?;
+    let inputs = Inputs::from_env();
+    for solution in event.solutions {
+        let day = solution.day();
+        if let Some(legacy) = inputs.migrate(year, day).map_err(|e| e.to_string())? {
+            println!(
+                "Moved {} to {}",
+                legacy.display(),
+                inputs.path(year, day).display()
+            );
+        }
+        for part in PARTS {
+            if let Some(legacy) = verify::migrate_output(event, day, part)? {
+                println!(
+                    "Moved {} to {}",
+                    legacy.display(),
+                    event.output_path(day, part).display()
+                );
+            }
+        }
     }
+    Ok(())
+}
+
+fn select_event(year: u16) -> std::result::Result<&'static Event, String> {
+    find_event(year).ok_or(format!("unknown year: {}", year))
+}
+
+fn select_tasks(
+    event: &Event,
+    target: &Target,
+) -> std::result::Result<Vec<(&'static dyn Solution, u16)>, String> {
+    let tasks = match *target {
+        Target::All => event
+            .solutions
+            .iter()
+            .flat_map(|&solution| PARTS.iter().map(move |&part| (solution, part)))
+            .collect(),
+        Target::Day { day, part } => {
+            let solution = event
+                .find_solution(day)
+                .ok_or(format!("unknown day: {}", day))?;
+            match part {
+                None => PARTS.iter().map(|&part| (solution, part)).collect(),
+                Some(part) if PARTS.contains(&part) => vec![(solution, part)],
+                Some(part) => return Err(format!("unknown part: {}", part)),
+            }
+        }
+    };
+    Ok(tasks)
+}
+
+fn read_stdin() -> std::result::Result<String, String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| format!("failed to read stdin: {}", e))?;
+    Ok(input)
+}
+
+fn read_input_file(path: &Path) -> std::result::Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|e| format!("failed to read input file {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::load_input;
+    use crate::verify::{details, verify_part, Status};
+    use advent_of_code_2021::inputs::{Inputs, DEFAULT_DIR};
+    use advent_of_code_2021::solution::{find_event, EVENTS, PARTS};
+    use std::fs;
+    use tempfile::TempDir;
 
-    fn out_path(file: &str) -> String {
-        format!("outputs/{}", file)
+    #[test]
+    fn test_load_input() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::write(dir.join("6.txt"), "3,4,3,1,2\n").unwrap();
+
+        let inputs = Inputs::new(dir);
+        let event = find_event(2021).unwrap();
+        let day_6 = event.find_solution(6).unwrap();
+        let day_7 = event.find_solution(7).unwrap();
+        // Legacy inputs are reported where they are read from.
+        assert_eq!(
+            load_input(&inputs, event, day_6, 1, false),
+            Ok(Some((dir.join("6.txt"), "3,4,3,1,2".to_string())))
+        );
+        assert_eq!(load_input(&inputs, event, day_7, 1, true), Ok(None));
+        assert!(load_input(&inputs, event, day_7, 1, false)
+            .unwrap_err()
+            .contains(&dir.join("2021/7.txt").display().to_string()));
     }
 
     #[test]
     fn test_with_inputs() {
-        assert_eq!(
-            day_1_1(in_path("1.1.txt")),
-            read_output(out_path("1.1.txt"))
-        );
-        assert_eq!(
-            day_1_2(in_path("1.1.txt")),
-            read_output(out_path("1.2.txt"))
-        );
-        assert_eq!(
-            day_2_1(in_path("2.1.txt")),
-            read_output(out_path("2.1.txt"))
-        );
-        assert_eq!(
-            day_2_2(in_path("2.1.txt")),
-            read_output(out_path("2.2.txt"))
-        );
-        assert_eq!(
-            day_3_1(in_path("3.1.txt")),
-            read_output(out_path("3.1.txt"))
-        );
-        assert_eq!(
-            day_3_2(in_path("3.1.txt")),
-            read_output(out_path("3.2.txt"))
-        );
-        assert_eq!(
-            day_4_1(in_path("4.1.txt")),
-            read_output(out_path("4.1.txt"))
-        );
-        assert_eq!(
-            day_4_2(in_path("4.1.txt")),
-            read_output(out_path("4.2.txt"))
-        );
-        assert_eq!(
-            day_5_1(in_path("5.1.txt")),
-            read_output(out_path("5.1.txt"))
-        );
-        assert_eq!(
-            day_5_2(in_path("5.1.txt")),
-            read_output(out_path("5.2.txt"))
-        );
-        assert_eq!(
-            day_6_1(in_path("6.1.txt")),
-            read_output(out_path("6.1.txt"))
-        );
-        assert_eq!(
-            day_6_2(in_path("6.1.txt")),
-            read_output(out_path("6.2.txt"))
-        );
-        assert_eq!(
-            day_7_1(in_path("7.1.txt")),
-            read_output(out_path("7.1.txt"))
-        );
-        assert_eq!(
-            day_7_2(in_path("7.1.txt")),
-            read_output(out_path("7.2.txt"))
-        );
-        assert_eq!(day_8_1(in_path("8.txt")), read_output(out_path("8.1.txt")));
-        assert_eq!(day_8_2(in_path("8.txt")), read_output(out_path("8.2.txt")));
-        assert_eq!(day_9_1(in_path("9.txt")), read_output(out_path("9.1.txt")));
-        assert_eq!(day_9_2(in_path("9.txt")), read_output(out_path("9.2.txt")));
-        assert_eq!(
-            day_10_1(in_path("10.txt")),
-            read_output(out_path("10.1.txt"))
-        );
-        assert_eq!(
-            day_10_2(in_path("10.txt")),
-            read_output(out_path("10.2.txt"))
-        );
-        assert_eq!(
-            day_11_1(in_path("11.txt")),
-            read_output(out_path("11.1.txt"))
-        );
-        assert_eq!(
-            day_11_2(in_path("11.txt")),
-            read_output(out_path("11.2.txt"))
-        );
-        assert_eq!(
-            day_12_1(in_path("12.txt")),
-            read_output(out_path("12.1.txt"))
-        );
-        assert_eq!(
-            day_12_2(in_path("12.txt")),
-            read_output(out_path("12.2.txt"))
-        );
-        assert_eq!(
-            day_13_1(in_path("13.txt")),
-            read_output(out_path("13.1.txt"))
-        );
-        assert_eq!(
-            day_13_2(in_path("13.txt")),
-            read_output::<_, String>(out_path("13.2.txt"))
-        );
-        assert_eq!(
-            day_14_1(in_path("14.txt")),
-            read_output(out_path("14.1.txt"))
-        );
-        assert_eq!(
-            day_14_2(in_path("14.txt")),
-            read_output(out_path("14.2.txt"))
-        );
-        assert_eq!(
-            day_15_1(in_path("15.txt")),
-            read_output(out_path("15.1.txt"))
-        );
-        assert_eq!(
-            day_15_2(in_path("15.txt")),
-            read_output(out_path("15.2.txt"))
-        );
-        assert_eq!(
-            day_16_1(in_path("16.txt")),
-            read_output(out_path("16.1.txt"))
-        );
-        assert_eq!(
-            day_16_2(in_path("16.txt")),
-            read_output(out_path("16.2.txt"))
-        );
-        assert_eq!(
-            day_17_1(in_path("17.txt")),
-            read_output(out_path("17.1.txt"))
-        );
-        assert_eq!(
-            day_17_2(in_path("17.txt")),
-            read_output(out_path("17.2.txt"))
-        );
-        assert_eq!(
-            day_18_1(in_path("18.txt")),
-            read_output(out_path("18.1.txt"))
-        );
-        assert_eq!(
-            day_18_2(in_path("18.txt")),
-            read_output(out_path("18.2.txt"))
-        );
-        // Those take too long when not running --release
-        #[cfg(not(debug_assertions))]
-        assert_eq!(
-            day_19_1(in_path("19.txt")),
-            read_output(out_path("19.1.txt"))
-        );
-        #[cfg(not(debug_assertions))]
-        assert_eq!(
-            day_19_2(in_path("19.txt")),
-            read_output(out_path("19.2.txt"))
-        );
-        assert_eq!(
-            day_20_1(in_path("20.txt")),
-            read_output(out_path("20.1.txt"))
-        );
-        assert_eq!(
-            day_20_2(in_path("20.txt")),
-            read_output(out_path("20.2.txt"))
-        );
-        assert_eq!(
-            day_21_1(in_path("21.txt")),
-            read_output(out_path("21.1.txt"))
-        );
-        assert_eq!(
-            day_21_2(in_path("21.txt")),
-            read_output(out_path("21.2.txt"))
-        );
-        assert_eq!(
-            day_22_1(in_path("22.txt")),
-            read_output(out_path("22.1.txt"))
-        );
-        assert_eq!(
-            day_22_2(in_path("22.txt")),
-            read_output(out_path("22.2.txt"))
-        );
-        assert_eq!(
-            day_23_1(in_path("23.txt")),
-            read_output(out_path("23.1.txt"))
-        );
-        assert_eq!(
-            day_23_2(in_path("23.txt")),
-            read_output(out_path("23.2.txt"))
-        );
-        assert_eq!(
-            day_24_1(in_path("24.txt")),
-            read_output(out_path("24.1.txt"))
-        );
-        assert_eq!(
-            day_24_2(in_path("24.txt")),
-            read_output(out_path("24.2.txt"))
-        );
-        assert_eq!(
-            day_25_1(in_path("25.txt")),
-            read_output(out_path("25.1.txt"))
-        );
+        let inputs = Inputs::new(DEFAULT_DIR);
+        let mut skipped = vec![];
+        for event in EVENTS.iter() {
+            for solution in event.solutions {
+                // Those take too long when not running --release
+                if cfg!(debug_assertions) && solution.is_slow() {
+                    skipped.push(format!("{} day {} (slow)", event.year, solution.day()));
+                    continue;
+                }
+
+                // Inputs and answers are not committed, check only the ones available locally.
+                for part in PARTS {
+                    let status = verify_part(event, *solution, part, &inputs, false);
+                    assert!(
+                        !status.is_failure(),
+                        "{}",
+                        details(solution.day(), part, &status).unwrap_or_default()
+                    );
+                    let reason = match status {
+                        Status::NoInput => "no input",
+                        Status::Missing => "no answer",
+                        _ => continue,
+                    };
+                    skipped.push(format!(
+                        "{} day {} part {} ({})",
+                        event.year,
+                        solution.day(),
+                        part,
+                        reason
+                    ));
+                }
+            }
+        }
+        if !skipped.is_empty() {
+            println!("Skipped {} checks:\n{}", skipped.len(), skipped.join("\n"));
+        }
     }
 }
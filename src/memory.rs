@@ -0,0 +1,158 @@
+//! Allocation statistics of solutions, collected by a counting global allocator
+//! which is only installed with the `memory-stats` feature.
+//!
+//! Allocations are counted per thread, so parts running in parallel are measured
+//! separately. Memory freed by another thread than the one which allocated it is
+//! not accounted for correctly, which solutions do not do.
+
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "memory-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    thread_local! {
+        // Const initialized without destructors, so the allocator can use them freely.
+        pub static CURRENT: Cell<i64> = const { Cell::new(0) };
+        pub static PEAK: Cell<i64> = const { Cell::new(0) };
+        pub static TOTAL: Cell<u64> = const { Cell::new(0) };
+    }
+
+    pub struct CountingAllocator;
+
+    fn allocated(size: usize) {
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + size as i64);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+        });
+        let _ = TOTAL.try_with(|total| total.set(total.get() + size as u64));
+    }
+
+    fn freed(size: usize) {
+        let _ = CURRENT.try_with(|current| current.set(current.get() - size as i64));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+}
+
+/// Memory allocated while running a closure.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Usage {
+    /// Highest amount of memory held at once, above what was held before.
+    pub peak: u64,
+    /// Sum of all allocations, including the ones already freed.
+    pub total: u64,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, total {}",
+            format_bytes(self.peak),
+            format_bytes(self.total)
+        )
+    }
+}
+
+pub fn is_enabled() -> bool {
+    cfg!(feature = "memory-stats")
+}
+
+/// Runs `f` and measures its allocations, there are none without the `memory-stats` feature.
+#[cfg(feature = "memory-stats")]
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    use counting::{CURRENT, PEAK, TOTAL};
+
+    let start = CURRENT.with(|current| current.get());
+    let outer_peak = PEAK.with(|peak| peak.replace(start));
+    let start_total = TOTAL.with(|total| total.get());
+
+    let result = f();
+
+    let peak = PEAK.with(|peak| peak.replace(outer_peak.max(peak.get())));
+    let usage = Usage {
+        peak: (peak - start).max(0) as u64,
+        total: TOTAL.with(|total| total.get()) - start_total,
+    };
+    (result, Some(usage))
+}
+
+/// Runs `f` and measures its allocations, there are none without the `memory-stats` feature.
+#[cfg(not(feature = "memory-stats"))]
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    (f(), None)
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        n if n < 1 << 10 => format!("{}B", n),
+        n if n < 1 << 20 => format!("{:.2}KiB", n as f64 / (1u64 << 10) as f64),
+        n if n < 1 << 30 => format!("{:.2}MiB", n as f64 / (1u64 << 20) as f64),
+        n => format!("{:.2}GiB", n as f64 / (1u64 << 30) as f64),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::memory::{format_bytes, is_enabled, measure, Usage};
+
+    #[test]
+    fn test() {
+        let (len, usage) = measure(|| {
+            let small = vec![0u8; 1000];
+            drop(small);
+            let (large, inner) = measure(|| vec![0u8; 4000]);
+            if is_enabled() {
+                assert_eq!(inner.map(|usage| usage.total), Some(4000));
+            }
+            large.len()
+        });
+        assert_eq!(len, 4000);
+        match usage {
+            Some(Usage { peak, total }) => {
+                assert!((4000..5000).contains(&peak), "peak {}", peak);
+                assert!(total >= 5000, "total {}", total);
+            }
+            None => assert!(!is_enabled()),
+        }
+
+        assert_eq!(format_bytes(1023), "1023B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(5 << 20), "5.00MiB");
+    }
+}
@@ -0,0 +1,178 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Field names of machine-readable output, in order of columns.
+pub const FIELDS: [&str; 5] = ["day", "part", "answer", "input", "elapsed_ms"];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "tsv" => Ok(Format::Tsv),
+            other => Err(other.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunResult {
+    pub day: u16,
+    pub part: u16,
+    pub answer: String,
+    pub input: PathBuf,
+    pub elapsed: Duration,
+}
+
+impl RunResult {
+    fn values(&self) -> [String; 5] {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer.clone(),
+            self.input.display().to_string(),
+            format!("{:.3}", self.elapsed.as_secs_f64() * 1e3),
+        ]
+    }
+}
+
+/// Renders results in a machine-readable format, `Format::Text` is printed
+/// by the runner as it goes, so it renders nothing.
+pub fn render(format: Format, results: &[RunResult]) -> String {
+    match format {
+        Format::Text => String::new(),
+        Format::Json => render_json(results),
+        Format::Csv => render_separated(results, ",", csv_escape),
+        Format::Tsv => render_separated(results, "\t", tsv_escape),
+    }
+}
+
+fn render_json(results: &[RunResult]) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|res| {
+            format!(
+                r#"  {{"day": {}, "part": {}, "answer": "{}", "input": "{}", "elapsed_ms": {:.3}}}"#,
+                res.day,
+                res.part,
+                json_escape(&res.answer),
+                json_escape(&res.input.display().to_string()),
+                res.elapsed.as_secs_f64() * 1e3
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+fn render_separated(results: &[RunResult], separator: &str, escape: fn(&str) -> String) -> String {
+    let mut out = FIELDS.join(separator);
+    out.push('\n');
+
+    for res in results {
+        let values: Vec<String> = res.values().iter().map(|v| escape(v)).collect();
+        out.push_str(&values.join(separator));
+        out.push('\n');
+    }
+    out
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+// TSV fields cannot contain tabs nor new lines, so those are escaped.
+fn tsv_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::output::{render, Format, RunResult};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn test() {
+        let results = vec![
+            RunResult {
+                day: 1,
+                part: 2,
+                answer: "1234".to_string(),
+                input: PathBuf::from("inputs/1.txt"),
+                elapsed: Duration::from_micros(1500),
+            },
+            RunResult {
+                day: 13,
+                part: 2,
+                answer: "\n#\t\"#\",".to_string(),
+                input: PathBuf::from("inputs/13.txt"),
+                elapsed: Duration::from_millis(2),
+            },
+        ];
+
+        assert_eq!(
+            render(Format::Json, &results),
+            r##"[
+  {"day": 1, "part": 2, "answer": "1234", "input": "inputs/1.txt", "elapsed_ms": 1.500},
+  {"day": 13, "part": 2, "answer": "\n#\t\"#\",", "input": "inputs/13.txt", "elapsed_ms": 2.000}
+]
+"##
+        );
+        assert_eq!(render(Format::Json, &[]), "[]\n");
+        assert_eq!(
+            render(Format::Csv, &results),
+            "day,part,answer,input,elapsed_ms
+1,2,1234,inputs/1.txt,1.500
+13,2,\"
+#\t\"\"#\"\",\",inputs/13.txt,2.000
+"
+        );
+        assert_eq!(
+            render(Format::Tsv, &results),
+            "day\tpart\tanswer\tinput\telapsed_ms
+1\t2\t1234\tinputs/1.txt\t1.500
+13\t2\t\\n#\\t\"#\",\tinputs/13.txt\t2.000
+"
+        );
+        assert_eq!(render(Format::Text, &results), "");
+    }
+}
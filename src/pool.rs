@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+
+/// Runs `work` on each item using up to `jobs` threads and passes the results to
+/// `report` in the order of `items`, as soon as all preceding ones are done.
+///
+/// Items are picked up in the order of `schedule`, except for the first thread which
+/// takes them from its end, so that long tasks placed first cannot occupy every thread.
+/// Once `report` fails no new items are started and its error is returned.
+pub fn run_ordered<T, R, E, W, F>(
+    items: &[T],
+    schedule: &[usize],
+    jobs: usize,
+    work: W,
+    mut report: F,
+) -> Result<(), E>
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    F: FnMut(R) -> Result<(), E>,
+{
+    if jobs <= 1 {
+        return items.iter().try_for_each(|item| report(work(item)));
+    }
+
+    let queue = Mutex::new(schedule.iter().copied().collect::<VecDeque<_>>());
+    let (work, queue) = (&work, &queue);
+
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for worker in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            scope.spawn(move || loop {
+                let next = {
+                    let mut queue = queue.lock().unwrap();
+                    if worker == 0 {
+                        queue.pop_back()
+                    } else {
+                        queue.pop_front()
+                    }
+                };
+                let i = match next {
+                    Some(i) => i,
+                    None => break,
+                };
+                if sender.send((i, work(&items[i]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut done: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        let mut next_report = 0;
+        for (i, result) in receiver {
+            done[i] = Some(result);
+            while let Some(result) = done.get_mut(next_report).and_then(Option::take) {
+                if let Err(err) = report(result) {
+                    queue.lock().unwrap().clear();
+                    return Err(err);
+                }
+                next_report += 1;
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pool::run_ordered;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test() {
+        let items: Vec<u64> = (0..20).collect();
+        let schedule: Vec<usize> = (0..20).rev().collect();
+        let work = |&item: &u64| {
+            thread::sleep(Duration::from_millis(20 - item));
+            item * 2
+        };
+
+        for jobs in [1, 3, 30] {
+            let mut reported = vec![];
+            let result: Result<(), ()> = run_ordered(&items, &schedule, jobs, work, |r| {
+                reported.push(r);
+                Ok(())
+            });
+            assert_eq!(result, Ok(()));
+            assert_eq!(reported, (0..20).map(|i| i * 2).collect::<Vec<_>>());
+        }
+
+        let mut reported = vec![];
+        let result = run_ordered(&items, &schedule, 4, work, |r| {
+            if r == 10 {
+                return Err(format!("failed at {}", r));
+            }
+            reported.push(r);
+            Ok(())
+        });
+        assert_eq!(result, Err("failed at 10".to_string()));
+        assert_eq!(reported, vec![0, 2, 4, 6, 8]);
+    }
+}
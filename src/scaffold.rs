@@ -0,0 +1,356 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_WIDTH: usize = 100;
+
+const TEMPLATE: &str = r#"use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{lines, read_input};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let _data = parse_input(input)?;
+    Err(Error::no_solution("part 1 is not solved yet"))
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let _data = parse_input(input)?;
+    Err(Error::no_solution("part 2 is not solved yet"))
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    Ok(lines(input).map(|line| line.text.to_string()).collect())
+}
+
+pub fn day_{N}_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_{N}_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    fn day(&self) -> u16 {
+        {N}
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(parse_input(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y{YEAR}::day{NN}::Day{NN};
+
+    const TEST_DATA: &str = "";
+
+    // Fill in the answers of the example from the puzzle description.
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: None,
+        part_2: None,
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day{NN}, EXAMPLES);
+    }
+}
+"#;
+
+/// Creates `src/y<year>/dayNN.rs` under `root` from the template and registers it in
+/// `SOLUTIONS` of the year, which is added to `EVENTS` when it is a new one. Returns
+/// the paths of the changed files.
+pub fn new_day(root: &Path, year: u16, day: u16) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, got {}", day));
+    }
+    let module = format!("day{:02}", day);
+    let year_module = format!("y{}", year);
+
+    let src = root.join("src");
+    let day_path = src.join(&year_module).join(format!("{}.rs", module));
+    if day_path.exists() {
+        return Err(format!(
+            "day {} of {} already exists: {}",
+            day,
+            year,
+            day_path.display()
+        ));
+    }
+    let year_path = src.join(format!("{}.rs", year_module));
+    let template = TEMPLATE
+        .replace("{YEAR}", &year.to_string())
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string());
+
+    // Files are updated only once all of them are known to be as expected.
+    let mut changed = vec![(day_path, template)];
+    if year_path.exists() {
+        let source = read(&year_path)?;
+        let registered = register_module(&source, "day", &module)
+            .and_then(|source| register_solution(&source, &module))
+            .ok_or_else(|| format!("no day modules found in {}", year_path.display()))?;
+        changed.push((year_path, registered));
+    } else {
+        let lib_path = src.join("lib.rs");
+        let lib = register_module(&read(&lib_path)?, "y", &year_module)
+            .ok_or_else(|| format!("no year modules found in {}", lib_path.display()))?;
+        let solution_path = src.join("solution.rs");
+        let solution = register_event(&read(&solution_path)?, year)
+            .ok_or_else(|| format!("no EVENTS found in {}", solution_path.display()))?;
+        changed.push((year_path, new_year_module(year, &module)));
+        changed.push((lib_path, lib));
+        changed.push((solution_path, solution));
+    }
+
+    fs::create_dir_all(src.join(&year_module)).map_err(|e| {
+        format!(
+            "failed to create {}: {}",
+            src.join(&year_module).display(),
+            e
+        )
+    })?;
+    for (path, content) in &changed {
+        fs::write(path, content)
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(changed.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
+
+/// Adds `pub mod <module>;` among the modules starting with `kind`, keeping them sorted.
+fn register_module(source: &str, kind: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {};", module);
+    let prefix = format!("pub mod {}", kind);
+    let modules: Vec<&str> = source
+        .lines()
+        .filter(|line| line.starts_with(&prefix))
+        .collect();
+    let last = *modules.last()?;
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let index = match modules.iter().find(|&&line| line > declaration.as_str()) {
+        Some(next) => lines.iter().position(|line| line == next)?,
+        None => lines.iter().position(|&line| line == last)? + 1,
+    };
+    lines.insert(index, &declaration);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the solution of the day module to `SOLUTIONS` of the year.
+fn register_solution(source: &str, module: &str) -> Option<String> {
+    let entry = format!("&{}::Day{}", module, &module[3..]);
+    register_entry(source, SOLUTIONS, entry)
+}
+
+/// Adds the event of the year to `EVENTS`.
+fn register_event(source: &str, year: u16) -> Option<String> {
+    let entry = format!("Event::new({}, &crate::y{}::SOLUTIONS)", year, year);
+    register_entry(source, EVENTS, entry)
+}
+
+const SOLUTIONS: &str = "pub static SOLUTIONS: [&dyn Solution; ";
+const EVENTS: &str = "pub static EVENTS: [Event; ";
+
+fn new_year_module(year: u16, module: &str) -> String {
+    let entry = format!("&{}::Day{}", module, &module[3..]);
+    format!(
+        "//! Solutions of the {} event.\n\nuse crate::solution::Solution;\n\npub mod {};\n\n{}\n",
+        year,
+        module,
+        format_array(SOLUTIONS, &[entry])
+    )
+}
+
+/// Adds `entry` to the sorted static array declared as `declaration`, updating its length.
+fn register_entry(source: &str, declaration: &str, entry: String) -> Option<String> {
+    let start = source.find(declaration)?;
+    let body = start + source[start..].find("= [")? + 3;
+    let end = body + source[body..].find("];")? + 2;
+    let mut entries = split_items(&source[body..end - 2]);
+    entries.push(entry);
+    entries.sort();
+    Some(format!(
+        "{}{}{}",
+        &source[..start],
+        format_array(declaration, &entries),
+        &source[end..]
+    ))
+}
+
+/// Splits a list on the commas which are not nested in parentheses.
+fn split_items(list: &str) -> Vec<String> {
+    let mut items = vec![];
+    let mut depth = 0;
+    let mut item = String::new();
+    for c in list.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(std::mem::take(&mut item));
+                continue;
+            }
+            _ => {}
+        }
+        item.push(c);
+    }
+    items.push(item);
+    items
+        .iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Formats the array the way rustfmt does, on a single line when it fits.
+fn format_array(declaration: &str, entries: &[String]) -> String {
+    let single = format!(
+        "{}{}] = [{}];",
+        declaration,
+        entries.len(),
+        entries.join(", ")
+    );
+    if single.len() <= MAX_WIDTH {
+        return single;
+    }
+    format!(
+        "{}{}] = [\n{}];",
+        declaration,
+        entries.len(),
+        entries
+            .iter()
+            .map(|entry| format!("    {},\n", entry))
+            .collect::<String>()
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::scaffold::{new_day, register_event, register_solution};
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    /// Asserts that rustfmt leaves the file as it is.
+    fn assert_formatted(path: &Path) {
+        let output = Command::new(option_env!("RUSTFMT").unwrap_or("rustfmt"))
+            .args(["--check", "--edition", "2018"])
+            .arg(path)
+            .output()
+            .expect("failed to run rustfmt");
+        assert!(
+            output.status.success(),
+            "{} is not formatted:\n{}",
+            path.display(),
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+
+    #[test]
+    fn test() {
+        let days = |days: &[u16]| -> String {
+            days.iter()
+                .map(|day| format!("    &day{:02}::Day{:02},\n", day, day))
+                .collect()
+        };
+        let without_day_7 = format!(
+            "pub static SOLUTIONS: [&dyn Solution; 9] = [\n{}];\n",
+            days(&[1, 2, 3, 4, 5, 6, 8, 9, 10])
+        );
+        assert_eq!(
+            register_solution(&without_day_7, "day07"),
+            Some(format!(
+                "pub static SOLUTIONS: [&dyn Solution; 10] = [\n{}];\n",
+                days(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+            ))
+        );
+
+        let solution =
+            "pub static EVENTS: [Event; 1] = [Event::new(2021, &crate::y2021::SOLUTIONS)];\n";
+        assert_eq!(
+            register_event(solution, 2022).as_deref(),
+            Some(
+                "pub static EVENTS: [Event; 2] = [\n    Event::new(2021, &crate::y2021::SOLUTIONS),\n    \
+                 Event::new(2022, &crate::y2022::SOLUTIONS),\n];\n"
+            )
+        );
+
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "//! Docs.\n\npub mod error;\npub mod y2020;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/solution.rs"),
+            solution.replace("2021", "2020"),
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/y2020.rs"),
+            "use crate::solution::Solution;\n\npub mod day01;\npub mod day03;\n\n\
+             pub static SOLUTIONS: [&dyn Solution; 2] = [&day01::Day01, &day03::Day03];\n",
+        )
+        .unwrap();
+
+        let changed = new_day(root, 2020, 2).unwrap();
+        assert_eq!(
+            changed,
+            vec![root.join("src/y2020/day02.rs"), root.join("src/y2020.rs")]
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2020.rs")).unwrap(),
+            "use crate::solution::Solution;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\n\
+             pub static SOLUTIONS: [&dyn Solution; 3] = [&day01::Day01, &day02::Day02, &day03::Day03];\n"
+        );
+        let day = fs::read_to_string(root.join("src/y2020/day02.rs")).unwrap();
+        assert!(day.contains("pub fn day_2_1<") && day.contains("impl Solution for Day02 {"));
+        assert!(day.contains("use crate::y2020::day02::Day02;"));
+        assert_formatted(&root.join("src/y2020/day02.rs"));
+
+        let changed = new_day(root, 2021, 4).unwrap();
+        assert_eq!(changed.len(), 4);
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "//! Docs.\n\npub mod error;\npub mod y2020;\npub mod y2021;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2021.rs")).unwrap(),
+            "//! Solutions of the 2021 event.\n\nuse crate::solution::Solution;\n\npub mod day04;\n\n\
+             pub static SOLUTIONS: [&dyn Solution; 1] = [&day04::Day04];\n"
+        );
+        assert_formatted(&root.join("src/y2021.rs"));
+        assert!(fs::read_to_string(root.join("src/solution.rs"))
+            .unwrap()
+            .contains("[Event; 2]"));
+
+        assert!(new_day(root, 2020, 2)
+            .unwrap_err()
+            .starts_with("day 2 of 2020 already exists"));
+        assert!(new_day(root, 2020, 26).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! Searches over state spaces shared by the days looking for the cheapest way
+//! to reach a goal or counting the ways to do so.
+
+use crate::trace;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// State space explored by the searches.
+pub trait SearchSpace {
+    type State: Clone + Eq + Hash;
+
+    /// States reachable from `state` in a single step along with the cost of the step.
+    fn neighbours(&self, state: &Self::State) -> Vec<(Self::State, u64)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// Estimated cost from `state` to the closest goal, used by `a_star`.
+    ///
+    /// It must never exceed the actual cost, otherwise the found path may not be the cheapest.
+    fn heuristic(&self, _state: &Self::State) -> u64 {
+        0
+    }
+}
+
+/// Path found by a search, `states` start with the initial state and end with the goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+/// Finds the cheapest path from `start` to a goal.
+pub fn dijkstra<S: SearchSpace>(space: &S, start: S::State) -> Option<Path<S::State>> {
+    cheapest_path(space, start, |_| 0)
+}
+
+/// Finds the cheapest path from `start` to a goal, exploring states with the lowest
+/// estimated total cost first.
+pub fn a_star<S: SearchSpace>(space: &S, start: S::State) -> Option<Path<S::State>> {
+    cheapest_path(space, start, |state| space.heuristic(state))
+}
+
+/// Finds the path to a goal with the fewest steps, the cost of the path is the number of steps.
+pub fn bfs<S: SearchSpace>(space: &S, start: S::State) -> Option<Path<S::State>> {
+    let mut parents = HashMap::new();
+    parents.insert(start.clone(), None);
+
+    let mut queue = VecDeque::from(vec![start]);
+    while let Some(state) = queue.pop_front() {
+        trace::count("states_expanded", 1);
+        if space.is_goal(&state) {
+            let states = reconstruct(&parents, state);
+            return Some(Path {
+                cost: states.len() as u64 - 1,
+                states,
+            });
+        }
+
+        for (next, _) in space.neighbours(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Counts distinct paths from `start` to goals, paths end at the first goal they reach.
+///
+/// The space must not have cycles reachable from `start`, as there would be endless paths.
+pub fn count_paths<S: SearchSpace>(space: &S, start: S::State) -> u64 {
+    fn count<S: SearchSpace>(space: &S, state: S::State, memo: &mut HashMap<S::State, u64>) -> u64 {
+        if space.is_goal(&state) {
+            return 1;
+        }
+        if let Some(&paths) = memo.get(&state) {
+            return paths;
+        }
+
+        let paths = space
+            .neighbours(&state)
+            .into_iter()
+            .map(|(next, _)| count(space, next, memo))
+            .sum();
+        trace::count("states_counted", 1);
+        memo.insert(state, paths);
+        paths
+    }
+
+    count(space, start, &mut HashMap::new())
+}
+
+struct Candidate<S> {
+    estimate: u64,
+    cost: u64,
+    state: S,
+}
+
+// Ordered by the estimate only and reversed, so that the max-heap pops the lowest one.
+impl<S> Ord for Candidate<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S> PartialOrd for Candidate<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> PartialEq for Candidate<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S> Eq for Candidate<S> {}
+
+fn cheapest_path<S, H>(space: &S, start: S::State, heuristic: H) -> Option<Path<S::State>>
+where
+    S: SearchSpace,
+    H: Fn(&S::State) -> u64,
+{
+    // Lowest known cost of reaching each state along with the state it is reached from.
+    let mut best: HashMap<S::State, (u64, Option<S::State>)> = HashMap::new();
+    best.insert(start.clone(), (0, None));
+
+    let mut open = BinaryHeap::new();
+    open.push(Candidate {
+        estimate: heuristic(&start),
+        cost: 0,
+        state: start,
+    });
+
+    while let Some(Candidate { cost, state, .. }) = open.pop() {
+        // State was reached more cheaply after this candidate had been queued.
+        if best
+            .get(&state)
+            .is_some_and(|&(best_cost, _)| cost > best_cost)
+        {
+            continue;
+        }
+        trace::count("states_expanded", 1);
+        if space.is_goal(&state) {
+            let parents = best
+                .into_iter()
+                .map(|(state, (_, parent))| (state, parent))
+                .collect();
+            return Some(Path {
+                cost,
+                states: reconstruct(&parents, state),
+            });
+        }
+
+        for (next, step_cost) in space.neighbours(&state) {
+            let next_cost = cost + step_cost;
+            if best
+                .get(&next)
+                .is_none_or(|&(best_cost, _)| next_cost < best_cost)
+            {
+                best.insert(next.clone(), (next_cost, Some(state.clone())));
+                open.push(Candidate {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+        trace::record_max("max_open", open.len() as u64);
+    }
+    None
+}
+
+fn reconstruct<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(Some(parent)) = states.last().and_then(|state| parents.get(state)) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod test {
+    use crate::search::{a_star, bfs, count_paths, dijkstra, Path, SearchSpace};
+
+    /// Numbers reachable by adding 1 for a cost of 1 or doubling for a cost of 3.
+    struct Numbers {
+        goal: u64,
+    }
+
+    impl SearchSpace for Numbers {
+        type State = u64;
+
+        fn neighbours(&self, &n: &u64) -> Vec<(u64, u64)> {
+            [(n + 1, 1), (n * 2, 3)]
+                .iter()
+                .copied()
+                .filter(|&(next, _)| next <= self.goal)
+                .collect()
+        }
+
+        fn is_goal(&self, &n: &u64) -> bool {
+            n == self.goal
+        }
+
+        // Every step costs at least 1, so the estimate is never too high.
+        fn heuristic(&self, &n: &u64) -> u64 {
+            if n == self.goal {
+                0
+            } else {
+                1
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let space = Numbers { goal: 20 };
+
+        let expected = Path {
+            cost: 10,
+            states: vec![1, 2, 3, 4, 5, 10, 20],
+        };
+        assert_eq!(dijkstra(&space, 1), Some(expected.clone()));
+        assert_eq!(a_star(&space, 1), Some(expected));
+
+        let fewest_steps = bfs(&space, 1).unwrap();
+        assert_eq!(fewest_steps.cost, 5);
+        assert_eq!(fewest_steps.states.first(), Some(&1));
+        assert_eq!(fewest_steps.states.last(), Some(&20));
+
+        assert_eq!(dijkstra(&space, 21), None);
+        assert_eq!(bfs(&space, 21), None);
+
+        // Both steps lead from 1 to 2, then to 4 either directly or through 3.
+        assert_eq!(count_paths(&Numbers { goal: 4 }, 1), 4);
+        assert_eq!(count_paths(&Numbers { goal: 4 }, 4), 1);
+    }
+}
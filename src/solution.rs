@@ -0,0 +1,101 @@
+use crate::error::{Error, Result};
+use std::path::PathBuf;
+
+/// Parts available for every day.
+pub const PARTS: [u16; 2] = [1, 2];
+
+/// Solution of a single day of the puzzle.
+///
+/// Every day module implements it and is registered in `SOLUTIONS` of its year, so
+/// that the runner and tests can enumerate days without keeping their own lists.
+pub trait Solution: Sync {
+    fn day(&self) -> u16;
+
+    fn part_1(&self, input: &str) -> Result<String>;
+
+    fn part_2(&self, input: &str) -> Result<String>;
+
+    /// Parses the input without solving, so that parsing can be timed separately.
+    fn parse(&self, input: &str) -> Result<()>;
+
+    /// Indicates that the solution takes too long to run without --release.
+    fn is_slow(&self) -> bool {
+        false
+    }
+
+    fn run_part(&self, part: u16, input: &str) -> Result<String> {
+        match part {
+            1 => self.part_1(input),
+            2 => self.part_2(input),
+            _ => Err(Error::UnknownPart(part)),
+        }
+    }
+}
+
+/// Year the runner uses unless told otherwise.
+pub const DEFAULT_YEAR: u16 = 2021;
+
+/// Solutions of a single year of the puzzle.
+pub struct Event {
+    pub year: u16,
+    pub solutions: &'static [&'static dyn Solution],
+}
+
+impl Event {
+    pub const fn new(year: u16, solutions: &'static [&'static dyn Solution]) -> Event {
+        Event { year, solutions }
+    }
+
+    pub fn find_solution(&self, day: u16) -> Option<&'static dyn Solution> {
+        self.solutions.iter().find(|s| s.day() == day).copied()
+    }
+
+    /// Expected answer of a part.
+    pub fn output_path(&self, day: u16, part: u16) -> PathBuf {
+        PathBuf::from(format!("outputs/{}/{}.{}.txt", self.year, day, part))
+    }
+
+    /// Expected answers used to be stored as `outputs/<day>.<part>.txt`, back when
+    /// there was only the default year.
+    pub fn legacy_output_path(&self, day: u16, part: u16) -> Option<PathBuf> {
+        (self.year == DEFAULT_YEAR).then(|| PathBuf::from(format!("outputs/{}.{}.txt", day, part)))
+    }
+}
+
+pub static EVENTS: [Event; 1] = [Event::new(2021, &crate::y2021::SOLUTIONS)];
+
+pub fn find_event(year: u16) -> Option<&'static Event> {
+    EVENTS.iter().find(|event| event.year == year)
+}
+
+pub fn find_solution(year: u16, day: u16) -> Option<&'static dyn Solution> {
+    find_event(year)?.find_solution(day)
+}
+
+/// Example input of a day along with the expected answers, `None` for parts
+/// the example does not cover.
+#[cfg(test)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+/// Runs the parts of `solution` on every example and checks their answers.
+#[cfg(test)]
+pub fn check_examples(solution: &dyn Solution, examples: &[Example]) {
+    for (i, example) in examples.iter().enumerate() {
+        for (part, expected) in PARTS.iter().zip([example.part_1, example.part_2]) {
+            if let Some(expected) = expected {
+                assert_eq!(
+                    solution.run_part(*part, example.input).as_deref(),
+                    Ok(expected),
+                    "day {} part {} example {}",
+                    solution.day(),
+                    part,
+                    i + 1
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use crate::error::{Error, Result};
+use crate::http;
+use crate::inputs::{BASE_URL_VAR, SESSION_VAR};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// The answer was not checked, as the previous one was submitted too recently.
+    TooSoon,
+    /// The part is either locked or already solved.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    fn from_response(text: &str) -> Verdict {
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooSoon => "too-soon",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        [
+            Verdict::Correct,
+            Verdict::Incorrect,
+            Verdict::TooSoon,
+            Verdict::WrongLevel,
+            Verdict::Unknown,
+        ]
+        .iter()
+        .copied()
+        .find(|verdict| verdict.label() == s)
+        .ok_or_else(|| Error::parse(format!("unknown verdict: {:?}", s)))
+    }
+}
+
+/// Single submission stored in the history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: u16,
+    pub part: u16,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Text of the response without markup.
+    pub response: String,
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.day,
+            self.part,
+            self.verdict,
+            escape(&self.answer),
+            escape(&self.response)
+        )
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        match fields[..] {
+            [timestamp, day, part, verdict, answer, response] => Ok(Attempt {
+                timestamp: timestamp.parse()?,
+                day: day.parse()?,
+                part: part.parse()?,
+                verdict: verdict.parse()?,
+                answer: unescape(answer),
+                response: unescape(response),
+            }),
+            _ => Err(Error::parse(format!(
+                "expected 6 fields, got {}",
+                fields.len()
+            ))),
+        }
+    }
+}
+
+/// Submits answers to `<base_url>/<year>/day/<day>/answer` and keeps the history of
+/// attempts, by default in `outputs/<year>/submissions.tsv` with one tab separated
+/// attempt per line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submitter {
+    year: u16,
+    base_url: String,
+    session: Option<String>,
+    history: PathBuf,
+}
+
+impl Submitter {
+    pub fn new<S: Into<String>>(year: u16, base_url: S, session: Option<String>) -> Submitter {
+        Submitter {
+            year,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+            history: PathBuf::from(format!("outputs/{}/submissions.tsv", year)),
+        }
+    }
+
+    /// Reads the configuration from `AOC_BASE_URL` and `AOC_SESSION`, the same as
+    /// [`Inputs::from_env`](crate::inputs::Inputs::from_env).
+    pub fn from_env(year: u16) -> Result<Submitter> {
+        let var = |name| {
+            env::var(name)
+                .ok()
+                .filter(|value: &String| !value.is_empty())
+        };
+
+        let base_url = var(BASE_URL_VAR)
+            .ok_or_else(|| Error::Submit(format!("{} is not set", BASE_URL_VAR)))?;
+        Ok(Submitter::new(year, base_url, var(SESSION_VAR)))
+    }
+
+    pub fn with_history<P: Into<PathBuf>>(self, history: P) -> Submitter {
+        Submitter {
+            history: history.into(),
+            ..self
+        }
+    }
+
+    /// Attempts in the order they were made, there are none when the history does not exist.
+    pub fn history(&self) -> Result<Vec<Attempt>> {
+        let content = match fs::read_to_string(&self.history) {
+            Ok(content) => content,
+            Err(_) if !self.history.exists() => return Ok(vec![]),
+            Err(e) => {
+                return Err(Error::Io(format!(
+                    "failed to read {}: {}",
+                    self.history.display(),
+                    e
+                )))
+            }
+        };
+
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| line.parse().map_err(|e: Error| e.at_line(i + 1)))
+            .collect()
+    }
+
+    /// Posts the answer unless the history already tells it is wrong or the part
+    /// is solved, every attempt is appended to the history.
+    pub fn submit(&self, day: u16, part: u16, answer: &str) -> Result<Attempt> {
+        let answer = answer.trim();
+        for attempt in self.history()? {
+            if (attempt.day, attempt.part) != (day, part) {
+                continue;
+            }
+            match attempt.verdict {
+                Verdict::Correct => {
+                    return Err(Error::Submit(format!(
+                        "day {} part {} is already solved with {}",
+                        day, part, attempt.answer
+                    )))
+                }
+                Verdict::Incorrect if attempt.answer == answer => {
+                    return Err(Error::Submit(format!(
+                        "{} is already known to be wrong for day {} part {}",
+                        answer, day, part
+                    )))
+                }
+                _ => {}
+            }
+        }
+
+        let url = self.answer_url(day);
+        let form = format!("level={}&answer={}", part, http::encode_form_value(answer));
+        let body = http::post_form(&url, self.session.as_deref(), &form).map_err(Error::Submit)?;
+
+        let response = response_text(&body);
+        let attempt = Attempt {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: Verdict::from_response(&response),
+            response,
+        };
+        self.record(&attempt)?;
+        Ok(attempt)
+    }
+
+    fn answer_url(&self, day: u16) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, self.year, day)
+    }
+
+    fn record(&self, attempt: &Attempt) -> Result<()> {
+        self.history
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.history)
+            })
+            .and_then(|mut file| writeln!(file, "{}", attempt))
+            .map_err(|e| Error::Io(format!("failed to store {}: {}", self.history.display(), e)))
+    }
+}
+
+/// Text of the `<article>` of the response, or of the whole response when there
+/// is none, with markup removed and whitespace collapsed.
+fn response_text(body: &str) -> String {
+    let article = body
+        .find("<article")
+        .and_then(|start| {
+            let end = body[start..].find("</article>")?;
+            Some(&body[start..start + end])
+        })
+        .unwrap_or(body);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::submit::{Attempt, Submitter, Verdict};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use tempfile::TempDir;
+
+    /// Accepts 42 as the answer of any part, the response is wrapped in markup
+    /// like the real one.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let mut reader = BufReader::new(&stream);
+                let head: Vec<String> = (&mut reader)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let length: usize = head
+                    .iter()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.parse().unwrap());
+                let mut form = vec![0; length];
+                reader.read_exact(&mut form).unwrap();
+
+                let text = match String::from_utf8(form).unwrap().as_str() {
+                    _ if !head[0].starts_with("POST /2021/day/") => "Not found",
+                    "level=1&answer=42" => "That's the right answer! <a href=\"/\">Return</a>",
+                    _ => "That's not the right answer.\tPlease wait one minute.",
+                };
+                let response = format!(
+                    "HTTP/1.0 200 OK\r\n\r\n<main>\n<article><p>{}</p></article>\n</main>",
+                    text
+                );
+                (&stream).write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test() {
+        let temp = TempDir::new().unwrap();
+        let history = temp.path().join("submissions.tsv");
+
+        let (base_url, requests) = serve();
+        let submitter = Submitter::new(2021, base_url, None).with_history(&history);
+        assert_eq!(submitter.history(), Ok(vec![]));
+
+        let wrong = submitter.submit(1, 1, "41\n").unwrap();
+        assert_eq!(wrong.verdict, Verdict::Incorrect);
+        assert_eq!(wrong.answer, "41");
+        assert_eq!(
+            wrong.response,
+            "That's not the right answer. Please wait one minute."
+        );
+
+        let err = submitter.submit(1, 1, "41").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to submit answer: 41 is already known to be wrong for day 1 part 1"
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let right = submitter.submit(1, 1, "42").unwrap();
+        assert_eq!(right.verdict, Verdict::Correct);
+        assert_eq!(right.response, "That's the right answer! Return");
+        assert!(matches!(
+            submitter.submit(1, 1, "43"),
+            Err(Error::Submit(_))
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        // History survives and round trips answers which do not fit on a line.
+        let art = Attempt {
+            answer: "#..#\n#\t\\".to_string(),
+            ..right.clone()
+        };
+        assert_eq!(art.to_string().parse(), Ok(art));
+
+        let site = Submitter::new(2021, "https://adventofcode.com/", None);
+        assert_eq!(
+            site.answer_url(1),
+            "https://adventofcode.com/2021/day/1/answer"
+        );
+        let ftp = Submitter::new(2021, "ftp://adventofcode.com", None).with_history(&history);
+        assert_eq!(
+            ftp.submit(2, 1, "42").unwrap_err().to_string(),
+            "failed to submit answer: ftp://adventofcode.com/2021/day/2/answer: \
+             only http:// and https:// URLs are supported"
+        );
+        assert_eq!(
+            Submitter::new(2021, "http://localhost", None)
+                .with_history(&history)
+                .history(),
+            Ok(vec![wrong, right])
+        );
+    }
+}
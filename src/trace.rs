@@ -0,0 +1,184 @@
+//! Lightweight tracing of long-running solvers, disabled unless `enable` is called.
+//!
+//! Spans measure the time spent in a scope and collect counters reported by the code
+//! running inside of them. Spans are tracked per thread and printed to stderr once the
+//! outermost span of the thread closes, so that parts running in parallel do not mix
+//! their lines.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static STATE: RefCell<ThreadState> = RefCell::new(ThreadState::default());
+}
+
+// Tests enable tracing of their own thread only, so that it does not leak into the
+// tests running in parallel with them.
+#[cfg(test)]
+thread_local! {
+    static ENABLED_ON_THREAD: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+#[derive(Default)]
+struct ThreadState {
+    open: Vec<Frame>,
+    opened: usize,
+    // Lines of closed spans waiting for the outermost span to close, along with
+    // the order in which the spans were opened.
+    lines: Vec<(usize, String)>,
+}
+
+struct Frame {
+    order: usize,
+    name: Cow<'static, str>,
+    start: Instant,
+    counters: Vec<(&'static str, u64)>,
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    #[cfg(test)]
+    if ENABLED_ON_THREAD.with(|enabled| enabled.get()) {
+        return true;
+    }
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Guard of an open span, the span closes when it is dropped.
+#[must_use = "the span closes as soon as the guard is dropped"]
+pub struct Span {
+    active: bool,
+}
+
+/// Opens a span nested in the current span of this thread.
+pub fn span<N: Into<Cow<'static, str>>>(name: N) -> Span {
+    if !is_enabled() {
+        return Span { active: false };
+    }
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let order = state.opened;
+        state.opened += 1;
+        state.open.push(Frame {
+            order,
+            name: name.into(),
+            start: Instant::now(),
+            counters: vec![],
+        })
+    });
+    Span { active: true }
+}
+
+/// Adds `value` to the counter of the current span.
+pub fn count(counter: &'static str, value: u64) {
+    update(counter, |total| *total += value);
+}
+
+/// Raises the counter of the current span to `value` if it is higher, e.g. to track
+/// the peak size of a collection.
+pub fn record_max(counter: &'static str, value: u64) {
+    update(counter, |max| *max = (*max).max(value));
+}
+
+fn update<F: FnOnce(&mut u64)>(counter: &'static str, f: F) {
+    if !is_enabled() {
+        return;
+    }
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let frame = match state.open.last_mut() {
+            Some(frame) => frame,
+            None => return,
+        };
+        match frame.counters.iter_mut().find(|(name, _)| *name == counter) {
+            Some((_, value)) => f(value),
+            None => {
+                let mut value = 0;
+                f(&mut value);
+                frame.counters.push((counter, value));
+            }
+        }
+    });
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let frame = match state.open.pop() {
+                Some(frame) => frame,
+                None => return,
+            };
+            let depth = state.open.len();
+            let line = format_frame(&frame, depth, frame.start.elapsed());
+            state.lines.push((frame.order, line));
+
+            if depth == 0 {
+                // Children close first, but are printed under their parent.
+                let mut lines = std::mem::take(&mut state.lines);
+                lines.sort_unstable_by_key(|&(order, _)| order);
+                state.opened = 0;
+
+                let text: String = lines.into_iter().map(|(_, line)| line + "\n").collect();
+                eprint!("{}", text);
+            }
+        });
+    }
+}
+
+fn format_frame(frame: &Frame, depth: usize, elapsed: Duration) -> String {
+    let mut line = format!(
+        "[trace] {}{} {:.2?}",
+        "  ".repeat(depth),
+        frame.name,
+        elapsed
+    );
+    for (name, value) in &frame.counters {
+        line.push_str(&format!(" {}={}", name, value));
+    }
+    line
+}
+
+#[cfg(test)]
+mod test {
+    use crate::trace::{count, record_max, span, ENABLED_ON_THREAD, STATE};
+
+    fn counters() -> Vec<(&'static str, u64)> {
+        STATE.with(|state| state.borrow().open.last().unwrap().counters.clone())
+    }
+
+    #[test]
+    fn test() {
+        ENABLED_ON_THREAD.with(|enabled| enabled.set(true));
+
+        let outer = span("outer");
+        count("steps", 2);
+        {
+            let _inner = span(format!("inner {}", 1));
+            count("steps", 5);
+            assert_eq!(counters(), vec![("steps", 5)]);
+        }
+        count("steps", 3);
+        record_max("peak", 4);
+        record_max("peak", 2);
+        assert_eq!(counters(), vec![("steps", 5), ("peak", 4)]);
+
+        drop(outer);
+        STATE.with(|state| {
+            let state = state.borrow();
+            assert!(state.open.is_empty());
+            assert!(state.lines.is_empty());
+        });
+        ENABLED_ON_THREAD.with(|enabled| enabled.set(false));
+    }
+}
@@ -1,30 +1,276 @@
-use std::env::temp_dir;
-use std::path::{Path, PathBuf};
+use crate::error::{Error, Result};
+use std::io::BufRead;
+use std::iter;
+use std::path::Path;
 use std::{fs, str::FromStr};
 
-pub fn read_lines_raw<P: AsRef<Path>>(file_name: P) -> Vec<String> {
-    let data = fs::read_to_string(file_name).expect("read data from file");
-    let data: Vec<String> = data.split('\n').map(|s| s.to_string()).collect();
+mod grid;
 
-    data
+pub use grid::{Grid, Pos};
+
+pub fn read_input<P: AsRef<Path>>(file_name: P) -> Result<String> {
+    Ok(fs::read_to_string(file_name)?)
+}
+
+/// Line of the input along with its number (1-based).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl Line<'_> {
+    /// Parses the line as T, errors are annotated with the line number.
+    pub fn parse<T: FromStr>(&self) -> Result<T>
+    where
+        <T as FromStr>::Err: Into<Error>,
+    {
+        self.text
+            .parse()
+            .map_err(|e: T::Err| e.into().at_line(self.number))
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// Iterates over lines of the data, `\r` of CRLF line endings is dropped and
+/// trailing blank lines are skipped.
+pub fn lines(data: &str) -> impl Iterator<Item = Line<'_>> {
+    lines_from(data, 1)
+}
+
+/// Same as `lines` for data starting at `first_line` of the input.
+pub fn lines_from(data: &str, first_line: usize) -> impl Iterator<Item = Line<'_>> {
+    let content = data.trim_end();
+    // Keep the last non-blank line whole, including its trailing spaces.
+    let end = data[content.len()..]
+        .find('\n')
+        .map_or(data.len(), |pos| content.len() + pos);
+
+    (!content.is_empty())
+        .then(|| data[..end].split('\n'))
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(move |(i, text)| Line {
+            number: first_line + i,
+            text: text.strip_suffix('\r').unwrap_or(text),
+        })
+}
+
+/// Lines of the input between blank lines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+
+impl Block<'_> {
+    pub fn first_line(&self) -> usize {
+        self.lines[0].number
+    }
+
+    /// Parses every line of the block as T.
+    pub fn parse_lines<T: FromStr>(&self) -> Result<Vec<T>>
+    where
+        <T as FromStr>::Err: Into<Error>,
+    {
+        self.lines.iter().map(Line::parse).collect()
+    }
+}
+
+/// Iterates over blocks of the data separated by one or more blank lines.
+pub fn blocks(data: &str) -> impl Iterator<Item = Block<'_>> {
+    let mut lines = lines(data).peekable();
+
+    iter::from_fn(move || {
+        while lines.next_if(Line::is_blank).is_some() {}
+        let block: Vec<Line> = iter::from_fn(|| lines.next_if(|line| !line.is_blank())).collect();
+        (!block.is_empty()).then_some(Block { lines: block })
+    })
 }
 
-pub fn read_lines<T: FromStr, P: AsRef<Path>>(file_name: P) -> Vec<T>
+/// Splits the data into exactly two blocks separated by blank lines.
+pub fn split_block_pair(data: &str) -> Result<(Block<'_>, Block<'_>)> {
+    let mut blocks = blocks(data);
+    match (blocks.next(), blocks.next(), blocks.next()) {
+        (Some(first), Some(second), None) => Ok((first, second)),
+        (_, _, Some(extra)) => {
+            Err(Error::parse("unexpected block after a blank line").at_line(extra.first_line()))
+        }
+        _ => Err(Error::parse(
+            "expected two blocks separated by a blank line",
+        )),
+    }
+}
+
+/// Parses every line as T, errors are annotated with the line number.
+pub fn parse_lines<T: FromStr>(data: &str) -> Result<Vec<T>>
 where
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    <T as FromStr>::Err: Into<Error>,
 {
-    let data = fs::read_to_string(file_name).expect("read data from file");
+    parse_records(data).collect()
+}
 
-    data.split('\n')
-        .into_iter()
-        .map(|s| s.parse().expect("cannot convert from &str to T"))
+/// Lazily parses every line as T, errors are annotated with the line number.
+pub fn parse_records<T: FromStr>(data: &str) -> impl Iterator<Item = Result<T>> + '_
+where
+    <T as FromStr>::Err: Into<Error>,
+{
+    lines(data).map(|line| line.parse())
+}
+
+/// Reads lines one by one with the same handling of line endings and trailing
+/// blank lines as `lines`, each line comes with its number.
+pub fn read_lines<R: BufRead>(reader: R) -> ReadLines<R> {
+    ReadLines {
+        reader,
+        number: 0,
+        held: vec![],
+    }
+}
+
+pub struct ReadLines<R> {
+    reader: R,
+    number: usize,
+    // Blank lines are only known not to be trailing once a non-blank one follows.
+    held: Vec<(usize, String)>,
+}
+
+impl<R: BufRead> ReadLines<R> {
+    fn read_line(&mut self) -> Result<Option<(usize, String)>> {
+        let mut text = String::new();
+        let read = self
+            .reader
+            .read_line(&mut text)
+            .map_err(|e| Error::Io(format!("failed to read line {}: {}", self.number + 1, e)))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        self.number += 1;
+
+        let len = text.trim_end_matches(['\n', '\r']).len();
+        text.truncate(len);
+        Ok(Some((self.number, text)))
+    }
+}
+
+impl<R: BufRead> Iterator for ReadLines<R> {
+    type Item = Result<(usize, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.held.is_empty() {
+            return Some(Ok(self.held.remove(0)));
+        }
+        loop {
+            match self.read_line() {
+                Ok(Some(line)) if line.1.trim().is_empty() => self.held.push(line),
+                Ok(Some(line)) => {
+                    self.held.push(line);
+                    return Some(Ok(self.held.remove(0)));
+                }
+                // Blank lines still held at the end are trailing ones.
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Lazily reads and parses every line as T, errors are annotated with the line number.
+pub fn read_records<T: FromStr, R: BufRead>(reader: R) -> impl Iterator<Item = Result<T>>
+where
+    <T as FromStr>::Err: Into<Error>,
+{
+    read_lines(reader).map(|line| {
+        let (number, text) = line?;
+        Line {
+            number,
+            text: &text,
+        }
+        .parse()
+    })
+}
+
+/// Parses a single line of values separated by `separator`, errors are
+/// annotated with the column of the malformed value.
+pub fn parse_separated<T: FromStr>(line: &str, separator: char) -> Result<Vec<T>>
+where
+    <T as FromStr>::Err: Into<Error>,
+{
+    let mut column = 1;
+    line.trim_end()
+        .split(separator)
+        .map(|s| {
+            let value = s.parse().map_err(|e: T::Err| e.into().at_column(column));
+            column += s.len() + separator.len_utf8();
+            value
+        })
         .collect()
 }
 
-pub fn temp_file_with_content(name: &str, content: &str) -> PathBuf {
-    let mut full_path = temp_dir();
-    full_path.push(name);
+pub fn split_pair<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(separator)
+        .ok_or_else(|| Error::parse(format!("expected {:?} in {:?}", separator, s)))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| Error::parse(format!("expected {:?} at the start of {:?}", prefix, s)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::util::{blocks, lines, parse_lines, read_records, split_block_pair, Line};
+
+    #[test]
+    fn test() {
+        let data = "1\r\n2\r\n\r\n\r\n3  \n\n \n";
+        let texts: Vec<(usize, &str)> = lines(data).map(|l| (l.number, l.text)).collect();
+        assert_eq!(
+            texts,
+            vec![(1, "1"), (2, "2"), (3, ""), (4, ""), (5, "3  ")]
+        );
+        assert_eq!(lines("\n\n").count(), 0);
+
+        let blocks: Vec<Vec<Line>> = blocks(data).map(|b| b.lines).collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(
+            blocks[1],
+            vec![Line {
+                number: 5,
+                text: "3  "
+            }]
+        );
+
+        let (first, second) = split_block_pair("1\n2\n\n3\n4\n").unwrap();
+        assert_eq!(first.parse_lines::<u32>(), Ok(vec![1, 2]));
+        assert_eq!(second.first_line(), 4);
+        assert_eq!(second.parse_lines::<u32>(), Ok(vec![3, 4]));
+        let err = split_block_pair("1\n\n2\n\n3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5: unexpected block after a blank line"
+        );
+
+        assert_eq!(parse_lines::<u32>("4\n5\n"), Ok(vec![4, 5]));
+        let err = parse_lines::<u32>("4\n\n5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: invalid number: cannot parse integer from empty string"
+        );
 
-    fs::write(&full_path, content).expect("failed to write temp file");
-    full_path
+        let records: Vec<Result<u32, Error>> =
+            read_records("7\r\nx\n\n8\n\n\n".as_bytes()).collect();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0], Ok(7));
+        assert_eq!(
+            records[1].as_ref().unwrap_err().to_string(),
+            "line 2: invalid number: invalid digit found in string"
+        );
+        assert!(records[2].is_err());
+        assert_eq!(records[3], Ok(8));
+    }
 }
@@ -0,0 +1,268 @@
+use crate::error::{Error, Result};
+use crate::util::{lines, Line};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// Position in a grid as `(row, column)`.
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+/// Rectangular grid stored row by row in a single vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    pub fn from_fn<F: FnMut(Pos) -> T>(rows: usize, cols: usize, mut f: F) -> Grid<T> {
+        let cells = (0..rows)
+            .flat_map(|r| (0..cols).map(move |c| (r, c)))
+            .map(&mut f)
+            .collect();
+        Grid { rows, cols, cells }
+    }
+
+    /// Parses a grid with a single character per cell, `parse_cell` errors are annotated
+    /// with the line and column of the character.
+    pub fn parse<F>(data: &str, parse_cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        Grid::parse_lines(lines(data), parse_cell)
+    }
+
+    /// Same as `parse` for lines of a larger input.
+    pub fn parse_lines<'a, I, F>(lines: I, mut parse_cell: F) -> Result<Grid<T>>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: FnMut(char) -> Result<T>,
+    {
+        let mut grid = Grid {
+            rows: 0,
+            cols: 0,
+            cells: vec![],
+        };
+        for line in lines {
+            let row_start = grid.cells.len();
+            for (c, ch) in line.text.chars().enumerate() {
+                let cell = parse_cell(ch).map_err(|e| e.at(line.number, c + 1))?;
+                grid.cells.push(cell);
+            }
+
+            let row_len = grid.cells.len() - row_start;
+            if grid.rows == 0 {
+                grid.cols = row_len;
+            } else if row_len != grid.cols {
+                return Err(Error::parse("rows differ in length").at_line(line.number));
+            }
+            grid.rows += 1;
+        }
+
+        if grid.cells.is_empty() {
+            return Err(Error::parse("grid is empty").at_line(1));
+        }
+        Ok(grid)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (r, c): Pos) -> Option<&T> {
+        self.contains((r, c))
+            .then(|| &self.cells[r * self.cols + c])
+    }
+
+    pub fn get_mut(&mut self, (r, c): Pos) -> Option<&mut T> {
+        if self.contains((r, c)) {
+            Some(&mut self.cells[r * self.cols + c])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.rows && c < self.cols
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterates over positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+
+    /// Iterates over cells along with their positions row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Position moved by `(rows, cols)`, if it is still inside the grid.
+    pub fn offset(&self, (r, c): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Position moved by `(rows, cols)`, wrapping around the edges as on a torus.
+    pub fn wrapping_offset(&self, (r, c): Pos, (dr, dc): (isize, isize)) -> Pos {
+        let wrap = |v: usize, d: isize, len: usize| (v as isize + d).rem_euclid(len as isize);
+        (
+            wrap(r, dr, self.rows) as usize,
+            wrap(c, dc, self.cols) as usize,
+        )
+    }
+
+    /// Up to 4 positions next to `pos` horizontally or vertically.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Up to 8 positions next to `pos`, including diagonal ones.
+    pub fn neighbours_with_diagonal(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .chain(DIAGONAL.iter())
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Draws the grid with a character per cell and rows on separate lines.
+    pub fn render<F: Fn(&T) -> char>(&self, cell_char: F) -> String {
+        (0..self.rows)
+            .map(|r| self.row(r).iter().map(&cell_char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a grid of single digits.
+    pub fn parse_digits(data: &str) -> Result<Grid<u32>> {
+        Grid::parse(data, |ch| {
+            ch.to_digit(10).ok_or_else(|| Error::unexpected_char(ch))
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): Pos) -> &T {
+        assert!(c < self.cols, "column {} out of {}", c, self.cols);
+        &self.cells[r * self.cols + c]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Pos) -> &mut T {
+        assert!(c < self.cols, "column {} out of {}", c, self.cols);
+        &mut self.cells[r * self.cols + c]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for r in 0..self.rows {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(r) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::util::Grid;
+
+    #[test]
+    fn test() {
+        let grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols(), grid.len()), (2, 3, 6));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let mut neighbours: Vec<_> = grid.neighbours((0, 0)).collect();
+        neighbours.sort_unstable();
+        assert_eq!(neighbours, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours((1, 1)).count(), 3);
+        assert_eq!(grid.neighbours_with_diagonal((1, 1)).count(), 5);
+        assert_eq!(grid.neighbours_with_diagonal((0, 0)).count(), 3);
+
+        assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (1, 2));
+        assert_eq!(grid.wrapping_offset((1, 2), (1, 1)), (0, 0));
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+
+        let doubled = grid.map(|v| v * 2);
+        assert_eq!(doubled.iter().sum::<u32>(), 42);
+        let rendered = doubled.render(|&v| if v > 6 { '#' } else { '.' });
+        assert_eq!(rendered, "...\n###");
+
+        let err = Grid::parse_digits("12\n3x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: unexpected character: 'x'"
+        );
+        let err = Grid::parse_digits("12\n345").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: rows differ in length");
+        let err = Grid::parse_digits("\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: grid is empty");
+    }
+}
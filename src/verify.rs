@@ -0,0 +1,198 @@
+use advent_of_code_2021::inputs::Inputs;
+use advent_of_code_2021::solution::{Event, Solution};
+use std::fs;
+use std::path::PathBuf;
+
+/// Outcome of checking a single part against its expected answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There is no expected answer to compare with.
+    Missing,
+    /// There was no expected answer, so the current one has been stored.
+    Recorded,
+    NoInput,
+    Error(String),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+            Status::Recorded => "recorded",
+            Status::NoInput => "no input",
+            Status::Error(_) => "ERROR",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+/// Path of the expected answer, an answer stored before outputs were split by year
+/// is read from its legacy location until it is migrated.
+pub fn output_path(event: &Event, day: u16, part: u16) -> PathBuf {
+    let path = event.output_path(day, part);
+    match event.legacy_output_path(day, part) {
+        Some(legacy) if !path.exists() && legacy.exists() => legacy,
+        _ => path,
+    }
+}
+
+/// Moves the expected answer stored at its legacy location to
+/// [`Event::output_path`], unless there is one already. Returns the legacy path
+/// it was moved from.
+pub fn migrate_output(event: &Event, day: u16, part: u16) -> Result<Option<PathBuf>, String> {
+    let path = event.output_path(day, part);
+    let legacy = match event.legacy_output_path(day, part) {
+        Some(legacy) if !path.exists() && legacy.exists() => legacy,
+        _ => return Ok(None),
+    };
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::rename(&legacy, &path))
+        .map_err(|e| format!("failed to rename {}: {}", legacy.display(), e))?;
+    Ok(Some(legacy))
+}
+
+/// Runs the part against its default input and compares the answer with the one
+/// stored in `outputs/`, when `record` is set missing answers are stored instead.
+pub fn verify_part(
+    event: &Event,
+    solution: &dyn Solution,
+    part: u16,
+    inputs: &Inputs,
+    record: bool,
+) -> Status {
+    let output_path = output_path(event, solution.day(), part);
+    let expected = fs::read_to_string(&output_path).ok();
+    if expected.is_none() && !record {
+        return Status::Missing;
+    }
+
+    if !inputs.is_available(event.year, solution.day()) {
+        return Status::NoInput;
+    }
+    let input = match inputs.load(event.year, solution.day()) {
+        Ok(input) => input,
+        Err(err) => return Status::Error(err.to_string()),
+    };
+    let actual = match solution.run_part(part, &input) {
+        Ok(answer) => answer,
+        Err(err) => return Status::Error(err.to_string()),
+    };
+
+    match expected {
+        Some(expected) if expected.trim_end() == actual.trim_end() => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.trim_end().to_string(),
+            actual: actual.trim_end().to_string(),
+        },
+        None => {
+            let stored = output_path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&output_path, format!("{}\n", actual)));
+            match stored {
+                Ok(_) => Status::Recorded,
+                Err(err) => Status::Error(format!(
+                    "failed to record {}: {}",
+                    output_path.display(),
+                    err
+                )),
+            }
+        }
+    }
+}
+
+pub fn matrix_header(parts: &[u16]) -> String {
+    let mut header = format!("{:>3}", "day");
+    for part in parts {
+        header.push_str(&format!("  {:<8}", format!("part {}", part)));
+    }
+    header.trim_end().to_string()
+}
+
+pub fn matrix_row(day: u16, statuses: &[Option<Status>]) -> String {
+    let mut row = format!("{:>3}", day);
+    for status in statuses {
+        let label = status.as_ref().map_or("", Status::label);
+        row.push_str(&format!("  {:<8}", label));
+    }
+    row.trim_end().to_string()
+}
+
+/// Describes the status in detail, only failures have something to add.
+pub fn details(day: u16, part: u16, status: &Status) -> Option<String> {
+    match status {
+        Status::Fail { expected, actual } => Some(format!(
+            "Day {} part {}: expected {:?}, got {:?}",
+            day, part, expected, actual
+        )),
+        Status::Error(err) => Some(format!("Day {} part {}: {}", day, part, err)),
+        _ => None,
+    }
+}
+
+pub fn summary(statuses: &[Status]) -> String {
+    let count = |label: &str| statuses.iter().filter(|s| s.label() == label).count();
+
+    let mut summary = format!(
+        "{} passed, {} failed, {} missing",
+        count("pass"),
+        count("FAIL") + count("ERROR"),
+        count("missing") + count("no input")
+    );
+    if count("recorded") > 0 {
+        summary.push_str(&format!(", {} recorded", count("recorded")));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod test {
+    use crate::verify::{details, matrix_header, matrix_row, summary, Status};
+
+    #[test]
+    fn test() {
+        let fail = Status::Fail {
+            expected: "12".to_string(),
+            actual: "13".to_string(),
+        };
+
+        assert_eq!(matrix_header(&[1, 2]), "day  part 1    part 2");
+        assert_eq!(
+            matrix_row(7, &[Some(Status::Pass), Some(fail.clone())]),
+            "  7  pass      FAIL"
+        );
+        assert_eq!(
+            matrix_row(12, &[None, Some(Status::Missing)]),
+            " 12            missing"
+        );
+
+        assert_eq!(
+            details(7, 2, &fail).as_deref(),
+            Some(r#"Day 7 part 2: expected "12", got "13""#)
+        );
+        assert_eq!(details(7, 1, &Status::Pass), None);
+
+        let statuses = [
+            Status::Pass,
+            fail,
+            Status::Error("boom".to_string()),
+            Status::NoInput,
+            Status::Recorded,
+        ];
+        assert_eq!(
+            summary(&statuses),
+            "1 passed, 2 failed, 1 missing, 1 recorded"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use crate::bench::format_duration;
+use crate::cli::WatchArgs;
+use crate::verify;
+use advent_of_code_2021::error::Result;
+use advent_of_code_2021::inputs::{self, Inputs};
+use advent_of_code_2021::solution::{Event, Solution, PARTS};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime};
+use std::{env, fs, thread};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Solves the selected parts whenever the content of the input changes, until
+/// interrupted. When the sources of the crate change the binary is rebuilt and
+/// restarted with the same arguments.
+pub fn watch(
+    event: &Event,
+    solution: &dyn Solution,
+    args: &WatchArgs,
+) -> std::result::Result<(), String> {
+    let parts: Vec<u16> = match args.part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let mut input = WatchedInput::select(event, solution, args, &Inputs::from_env());
+    let sources = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    // Resolved before any rebuild replaces the binary.
+    let exe = env::current_exe().map_err(|e| format!("failed to locate the binary: {}", e))?;
+
+    println!(
+        "Watching {} and {}, press Ctrl-C to stop",
+        input.path.display(),
+        sources.display()
+    );
+    let mut sources_modified = last_modified(&sources);
+    let mut answers: Vec<Option<String>> = vec![None; parts.len()];
+    loop {
+        match input.poll() {
+            Some(Ok(content)) => {
+                for (&part, previous) in parts.iter().zip(answers.iter_mut()) {
+                    println!("\n{}", solve(event, solution, part, &content, previous));
+                }
+            }
+            Some(Err(err)) => println!("\nWaiting for {}: {}", input.path.display(), err),
+            None => {}
+        }
+
+        let modified = last_modified(&sources);
+        if modified != sources_modified {
+            sources_modified = modified;
+            println!("\nSources changed, rebuilding");
+            if rebuild() {
+                return restart(&exe);
+            }
+            println!("Build failed, waiting for further changes");
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Input file polled for changes of its content.
+struct WatchedInput {
+    path: PathBuf,
+    /// Inputs of the inputs directory have their trailing newlines stripped, as `run` does.
+    normalize: bool,
+    /// Modification time seen by the last poll, `None` before the first one so that the
+    /// file is always read once.
+    modified: Option<Option<SystemTime>>,
+    content: Option<String>,
+    waiting: bool,
+}
+
+impl WatchedInput {
+    /// Watches the input given by the arguments, or else the one `run` would read.
+    fn select(event: &Event, solution: &dyn Solution, args: &WatchArgs, inputs: &Inputs) -> Self {
+        match &args.input {
+            Some(path) => WatchedInput::new(path.clone(), false),
+            None => {
+                // Loading once downloads a missing input, the same as `run` would.
+                if let Err(err) = inputs.load(event.year, solution.day()) {
+                    eprintln!("{}", err);
+                }
+                WatchedInput::new(inputs.resolve(event.year, solution.day()), true)
+            }
+        }
+    }
+
+    fn new(path: PathBuf, normalize: bool) -> WatchedInput {
+        WatchedInput {
+            path,
+            normalize,
+            modified: None,
+            content: None,
+            waiting: false,
+        }
+    }
+
+    /// Reads the file when it was modified since the last poll. Gives the content if it
+    /// changed, or the error of reading it, which is reported once until it can be read.
+    fn poll(&mut self) -> Option<std::result::Result<String, String>> {
+        let modified = last_modified(&self.path);
+        if self.modified == Some(modified) {
+            return None;
+        }
+        self.modified = Some(modified);
+        match fs::read_to_string(&self.path) {
+            Ok(content) => {
+                self.waiting = false;
+                let content = if self.normalize {
+                    inputs::normalize(&content).to_string()
+                } else {
+                    content
+                };
+                // Saving the file without changing it does not need a new run.
+                if self.content.as_ref() == Some(&content) {
+                    return None;
+                }
+                self.content = Some(content.clone());
+                Some(Ok(content))
+            }
+            Err(_) if self.waiting => None,
+            Err(err) => {
+                self.waiting = true;
+                Some(Err(err.to_string()))
+            }
+        }
+    }
+}
+
+/// Solves the part and describes the outcome, `previous` is updated with the answer.
+fn solve(
+    event: &Event,
+    solution: &dyn Solution,
+    part: u16,
+    input: &str,
+    previous: &mut Option<String>,
+) -> String {
+    let start = Instant::now();
+    let answer = solution.run_part(part, input);
+    let elapsed = start.elapsed();
+    let expected = fs::read_to_string(verify::output_path(event, solution.day(), part)).ok();
+    let lines = format!(
+        "Day {} part {} ({})\n{}",
+        solution.day(),
+        part,
+        format_duration(elapsed),
+        report(&answer, previous.as_deref(), expected.as_deref())
+    );
+    if let Ok(answer) = answer {
+        *previous = Some(answer);
+    }
+    lines
+}
+
+/// Describes the outcome of a run, compared with the answer of the previous run
+/// and the expected one.
+fn report(answer: &Result<String>, previous: Option<&str>, expected: Option<&str>) -> String {
+    let answer = match answer {
+        Ok(answer) => answer.trim_end(),
+        Err(err) => return format!("Error: {}", err),
+    };
+    let mut lines = vec![format!("Output: {}", answer)];
+
+    match previous.map(str::trim_end) {
+        None => {}
+        Some(previous) if previous == answer => lines.push("Unchanged".to_string()),
+        Some(previous) => {
+            lines.push("Changed:".to_string());
+            lines.extend(diff(previous, answer));
+        }
+    }
+    match expected.map(str::trim_end) {
+        None => lines.push("Expected: missing".to_string()),
+        Some(expected) if expected == answer => lines.push("Expected: pass".to_string()),
+        Some(expected) => {
+            lines.push("Expected: FAIL".to_string());
+            lines.extend(diff(expected, answer));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Lines which differ between the two texts, compared line by line.
+fn diff(old: &str, new: &str) -> Vec<String> {
+    let (old, new): (Vec<&str>, Vec<&str>) = (old.lines().collect(), new.lines().collect());
+    let mut lines = vec![];
+    for i in 0..old.len().max(new.len()) {
+        let (old, new) = (old.get(i), new.get(i));
+        if old != new {
+            lines.extend(old.map(|line| format!("- {}", line)));
+            lines.extend(new.map(|line| format!("+ {}", line)));
+        }
+    }
+    lines
+}
+
+/// Latest modification time of the file, or of any file in the directory.
+fn last_modified(path: &Path) -> Option<SystemTime> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return metadata.modified().ok();
+    }
+    fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| last_modified(&entry.path()))
+        .max()
+}
+
+/// Builds the binary with the profile and features of the running one.
+fn rebuild() -> bool {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    cargo
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    if cfg!(feature = "memory-stats") {
+        cargo.args(["--features", "memory-stats"]);
+    }
+    cargo.status().is_ok_and(|status| status.success())
+}
+
+#[cfg(unix)]
+fn restart(exe: &Path) -> std::result::Result<(), String> {
+    use std::os::unix::process::CommandExt;
+
+    let err = Command::new(exe).args(env::args_os().skip(1)).exec();
+    Err(format!("failed to restart {}: {}", exe.display(), err))
+}
+
+#[cfg(not(unix))]
+fn restart(exe: &Path) -> std::result::Result<(), String> {
+    let status = Command::new(exe)
+        .args(env::args_os().skip(1))
+        .status()
+        .map_err(|e| format!("failed to restart {}: {}", exe.display(), e))?;
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cli::WatchArgs;
+    use crate::watch::{report, WatchedInput};
+    use advent_of_code_2021::error::Error;
+    use advent_of_code_2021::inputs::Inputs;
+    use advent_of_code_2021::solution::find_event;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_watched_input() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::write(dir.join("6.txt"), "3,4,3,1,2\n").unwrap();
+
+        let event = find_event(2021).unwrap();
+        let inputs = Inputs::new(dir);
+        let args = |day, input| WatchArgs {
+            year: 2021,
+            day,
+            part: None,
+            input,
+        };
+        let solution = event.find_solution(6).unwrap();
+        let mut input = WatchedInput::select(event, solution, &args(6, None), &inputs);
+        // Legacy inputs are watched where they are, and read like `run` reads them.
+        assert_eq!(input.path, dir.join("6.txt"));
+        assert_eq!(input.poll(), Some(Ok("3,4,3,1,2".to_string())));
+        assert_eq!(input.poll(), None);
+
+        // Missing inputs are reported once, and read as soon as they appear.
+        let solution = event.find_solution(7).unwrap();
+        let mut input = WatchedInput::select(event, solution, &args(7, None), &inputs);
+        assert_eq!(input.path, dir.join("2021/7.txt"));
+        assert!(matches!(input.poll(), Some(Err(_))));
+        assert_eq!(input.poll(), None);
+        fs::create_dir(dir.join("2021")).unwrap();
+        fs::write(dir.join("2021/7.txt"), "16,1,2\n").unwrap();
+        assert_eq!(input.poll(), Some(Ok("16,1,2".to_string())));
+
+        // Files given explicitly are read as they are.
+        let path = dir.join("2021/7.txt");
+        let mut input = WatchedInput::select(event, solution, &args(7, Some(path)), &inputs);
+        assert_eq!(input.poll(), Some(Ok("16,1,2\n".to_string())));
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(
+            report(&Ok("42".to_string()), None, None),
+            "Output: 42\nExpected: missing"
+        );
+        assert_eq!(
+            report(&Ok("42".to_string()), Some("42"), Some("42\n")),
+            "Output: 42\nUnchanged\nExpected: pass"
+        );
+        assert_eq!(
+            report(&Ok("43".to_string()), Some("42"), Some("42\n")),
+            "Output: 43\nChanged:\n- 42\n+ 43\nExpected: FAIL\n- 42\n+ 43"
+        );
+        assert_eq!(
+            report(&Ok("#.#\n.#.\n#.#".to_string()), Some("#.#\n###"), None),
+            "Output: #.#\n.#.\n#.#\nChanged:\n- ###\n+ .#.\n+ #.#\nExpected: missing"
+        );
+        assert_eq!(
+            report(&Err(Error::no_solution("stuck")), Some("42"), None),
+            format!("Error: {}", Error::no_solution("stuck"))
+        );
+    }
+}
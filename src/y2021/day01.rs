@@ -0,0 +1,64 @@
+use crate::error::Result;
+use crate::solution::Solution;
+use crate::util::{parse_lines, read_input};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<u32> {
+    let data: Vec<i32> = parse_lines(input)?;
+
+    let mut inc_count: u32 = 0;
+    for i in 1..data.len() {
+        if data[i] > data[i - 1] {
+            inc_count += 1
+        }
+    }
+
+    Ok(inc_count)
+}
+
+pub fn part_2(input: &str) -> Result<u32> {
+    let data: Vec<i32> = parse_lines(input)?;
+
+    let mut inc_count: u32 = 0;
+    let mut prev_sum: i32 = data.iter().take(3).sum();
+
+    for i in 1..data.len().saturating_sub(2) {
+        let curr_sum = prev_sum - data[i - 1] + data[i + 2];
+        if curr_sum > prev_sum {
+            inc_count += 1
+        }
+        prev_sum = curr_sum
+    }
+
+    Ok(inc_count)
+}
+
+pub fn day_1_1<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_1_2<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u16 {
+        1
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        let _: Vec<i32> = black_box(parse_lines(input)?);
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::util::read_lines;
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_lines, read_input};
+use std::hint::black_box;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -9,14 +12,14 @@ enum Direction {
 }
 
 impl FromStr for Direction {
-    type Err = ParseError;
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> Result<Self> {
         match s {
             "up" => Ok(Direction::Up),
             "down" => Ok(Direction::Down),
             "forward" => Ok(Direction::Forward),
-            other => Err(ParseError::UnexpectedDirectionError(other.to_owned())),
+            other => Err(Error::parse(format!("unexpected direction: {:?}", other))),
         }
     }
 }
@@ -27,12 +30,12 @@ struct Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ParseError;
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(" ").collect();
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split(' ').collect();
         if parts.len() != 2 {
-            return Err(ParseError::InvalidSplitError(format!(
+            return Err(Error::parse(format!(
                 "expected 2 parts, got {}",
                 parts.len()
             )));
@@ -40,21 +43,14 @@ impl FromStr for Instruction {
 
         let instruction = Instruction {
             dir: Direction::from_str(parts[0])?,
-            value: parts[1].parse().map_err(|e| ParseError::ParseIntError(e))?,
+            value: parts[1].parse()?,
         };
         Ok(instruction)
     }
 }
 
-#[derive(Debug)]
-enum ParseError {
-    InvalidSplitError(String),
-    UnexpectedDirectionError(String),
-    ParseIntError(std::num::ParseIntError),
-}
-
-pub fn day_2_1<P: AsRef<Path>>(input_file: P) -> u32 {
-    let instructions: Vec<Instruction> = read_lines(input_file);
+pub fn part_1(input: &str) -> Result<u32> {
+    let instructions: Vec<Instruction> = parse_lines(input)?;
 
     let mut depth: u32 = 0;
     let mut pos: u32 = 0;
@@ -76,11 +72,11 @@ pub fn day_2_1<P: AsRef<Path>>(input_file: P) -> u32 {
         };
     }
 
-    depth * pos
+    Ok(depth * pos)
 }
 
-pub fn day_2_2<P: AsRef<Path>>(input_file: P) -> u32 {
-    let instructions: Vec<Instruction> = read_lines(input_file);
+pub fn part_2(input: &str) -> Result<u32> {
+    let instructions: Vec<Instruction> = parse_lines(input)?;
 
     let mut depth: u32 = 0;
     let mut pos: u32 = 0;
@@ -106,5 +102,34 @@ pub fn day_2_2<P: AsRef<Path>>(input_file: P) -> u32 {
         };
     }
 
-    depth * pos
+    Ok(depth * pos)
+}
+
+pub fn day_2_1<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_2_2<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u16 {
+        2
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        let _: Vec<Instruction> = black_box(parse_lines(input)?);
+        Ok(())
+    }
 }
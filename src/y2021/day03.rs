@@ -0,0 +1,152 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{lines, read_input};
+use std::hint::black_box;
+use std::path::Path;
+
+/// Diagnostic report, numbers of `width` bits each.
+struct Report {
+    numbers: Vec<usize>,
+    width: usize,
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let Report { numbers, width } = read_report(input)?;
+
+    let mut gamma: usize = 0;
+    let mut epsilon: usize = 0;
+
+    for shift in (0..width).rev() {
+        if count_ones_at_pos(&numbers, shift) > numbers.len() / 2 {
+            gamma += 1 << shift
+        } else {
+            epsilon += 1 << shift
+        }
+    }
+
+    Ok(gamma * epsilon)
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let Report { numbers, width } = read_report(input)?;
+
+    let ox_gen_rating = filter_by_bit_criteria(numbers.clone(), width, 1)?;
+    let co2_scrub_rating = filter_by_bit_criteria(numbers, width, 0)?;
+
+    Ok(ox_gen_rating * co2_scrub_rating)
+}
+
+fn read_report(input: &str) -> Result<Report> {
+    let mut lines = lines(input).peekable();
+    let width = lines
+        .peek()
+        .ok_or_else(|| Error::parse("empty report"))?
+        .text
+        .len();
+    // Every bit must fit, shifting by the width included.
+    if width >= usize::BITS as usize {
+        return Err(Error::parse("number is too wide").at_line(1));
+    }
+
+    let numbers = lines
+        .map(|line| {
+            if line.text.len() != width {
+                let message = format!("expected {} bits, found {}", width, line.text.len());
+                return Err(Error::parse(message).at_line(line.number));
+            }
+            usize::from_str_radix(line.text, 2).map_err(|e| Error::from(e).at_line(line.number))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Report { numbers, width })
+}
+
+fn filter_by_bit_criteria(mut nums: Vec<usize>, word_len: usize, default: usize) -> Result<usize> {
+    let mut shift = word_len;
+    while nums.len() != 1 {
+        if shift == 0 {
+            return Err(Error::no_solution(
+                "bit criteria do not select a single number",
+            ));
+        }
+        shift -= 1;
+        let filter_val = if count_ones_at_pos(&nums, shift) >= nums.len().div_ceil(2) {
+            default
+        } else {
+            default ^ 1
+        };
+
+        nums.retain(|n| (*n >> shift) % 2 == filter_val);
+    }
+
+    Ok(nums[0])
+}
+
+fn count_ones_at_pos(nums: &[usize], shift: usize) -> usize {
+    nums.iter().filter(|n| ((**n) >> shift) % 2 == 1).count()
+}
+
+pub fn day_3_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_3_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u16 {
+        3
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_report(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day03::{part_1, part_2, Day03};
+
+    const TEST_DATA: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("198"),
+        part_2: Some("230"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day03, EXAMPLES);
+
+        assert_eq!(part_1("").unwrap_err().to_string(), "empty report");
+        let err = part_2("0010\n110\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected 4 bits, found 3");
+        let err = part_1("0010\n0120\n").unwrap_err();
+        assert!(err.to_string().starts_with("line 2: "));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{blocks, parse_separated, read_input, Block};
+use std::hint::black_box;
+use std::path::Path;
+
+struct Board {
+    pub board: Vec<Vec<(u32, bool)>>,
+}
+
+impl Board {
+    fn new_from_block(block: &Block) -> Result<Board> {
+        let board: Vec<Vec<(u32, bool)>> = block
+            .lines
+            .iter()
+            .map(|line| {
+                line.text
+                    .split(' ')
+                    .filter(|elem| !elem.is_empty())
+                    .map(|num| Ok((num.parse()?, false)))
+                    .collect::<Result<_>>()
+                    .map_err(|e| e.at_line(line.number))
+            })
+            .collect::<Result<_>>()?;
+
+        if board.iter().any(|row| row.len() != board.len()) {
+            return Err(Error::parse("board is not square").at_line(block.first_line()));
+        }
+
+        Ok(Board { board })
+    }
+
+    fn mark_num(&mut self, num: u32) {
+        self.board.iter_mut().for_each(|row| {
+            for record in row.iter_mut() {
+                if record.0 == num {
+                    record.1 = true
+                }
+            }
+        });
+    }
+
+    fn calc_score(&self) -> Option<u32> {
+        let mut sum = 0;
+        let mut won = false;
+
+        for r in &self.board {
+            let mut finished = 0;
+            for (elem, marked) in r {
+                if *marked {
+                    finished += 1;
+                } else {
+                    sum += elem;
+                }
+            }
+            if finished == r.len() {
+                won = true;
+            }
+        }
+
+        // Exit early if row complete
+        if won {
+            return Some(sum);
+        }
+
+        for i in 0..self.board.len() {
+            let mut finished = 0;
+            for k in 0..self.board[0].len() {
+                if self.board[k][i].1 {
+                    finished += 1;
+                }
+            }
+            if finished == self.board.len() {
+                return Some(sum);
+            }
+        }
+        None
+    }
+}
+
+pub fn part_1(input: &str) -> Result<u32> {
+    let (draws, mut boards) = parse_input(input)?;
+
+    for draw in draws {
+        for board in boards.iter_mut() {
+            board.mark_num(draw);
+            if let Some(score) = board.calc_score() {
+                return Ok(score * draw);
+            }
+        }
+    }
+
+    Ok(0)
+}
+
+pub fn part_2(input: &str) -> Result<u32> {
+    let (draws, mut boards) = parse_input(input)?;
+
+    let mut scores: Vec<u32> = Vec::with_capacity(boards.len());
+
+    let mut draw_idx = 0;
+    while draw_idx < draws.len() && !boards.is_empty() {
+        let draw = draws[draw_idx];
+        let mut rem = Vec::new();
+        for (i, board) in boards.iter_mut().enumerate() {
+            board.mark_num(draw);
+            if let Some(score) = board.calc_score() {
+                rem.push(i);
+                scores.push(score * draw);
+            }
+        }
+
+        // Remove bards that already won
+        boards = boards
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !rem.contains(i))
+            .map(|(_, b)| b)
+            .collect();
+        draw_idx += 1;
+    }
+
+    scores
+        .last()
+        .copied()
+        .ok_or_else(|| Error::no_solution("no board wins"))
+}
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<Board>)> {
+    let mut blocks = blocks(input);
+    let draws = match blocks.next() {
+        Some(block) if block.lines.len() == 1 => block.lines[0],
+        Some(block) => {
+            let extra_line = block.first_line() + 1;
+            return Err(Error::parse("draws must be a single line").at_line(extra_line));
+        }
+        None => return Err(Error::parse("missing draws")),
+    };
+    let draws: Vec<u32> = parse_separated(draws.text, ',').map_err(|e| e.at_line(draws.number))?;
+
+    let boards = blocks
+        .map(|block| Board::new_from_block(&block))
+        .collect::<Result<_>>()?;
+
+    Ok((draws, boards))
+}
+
+pub fn day_4_1<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_4_2<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u16 {
+        4
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(parse_input(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day04::{part_1, Day04};
+
+    const TEST_DATA: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("4512"),
+        part_2: Some("1924"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day04, EXAMPLES);
+
+        let crlf = format!("{}\r\n\r\n", TEST_DATA.replace('\n', "\r\n"));
+        assert_eq!(part_1(&crlf), Ok(4512));
+    }
+}
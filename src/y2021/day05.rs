@@ -1,4 +1,7 @@
-use crate::util::read_lines;
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_lines, read_input, split_pair};
+use std::hint::black_box;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -42,13 +45,13 @@ impl Line {
     fn apply_line(&self, diagram: &mut [Vec<usize>], ignore_diag: bool) {
         match self.orientation {
             Orientation::Horizontal => {
-                for i in self.min_x()..self.max_x() + 1 {
-                    diagram[self.p1.1][i] += 1;
+                for val in diagram[self.p1.1][self.min_x()..self.max_x() + 1].iter_mut() {
+                    *val += 1;
                 }
             }
             Orientation::Vertical => {
-                for i in self.min_y()..self.max_y() + 1 {
-                    diagram[i][self.p1.0] += 1;
+                for row in diagram[self.min_y()..self.max_y() + 1].iter_mut() {
+                    row[self.p1.0] += 1;
                 }
             }
             Orientation::Diagonal => {
@@ -83,33 +86,37 @@ impl Line {
 }
 
 impl FromStr for Line {
-    type Err = ();
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let points: Vec<&str> = s.split("->").collect();
+    fn from_str(s: &str) -> Result<Self> {
+        let (p1, p2) = split_pair(s, "->")?;
 
-        let p1 = point_from_str(points[0]);
-        let p2 = point_from_str(points[1]);
+        let p1 = point_from_str(p1)?;
+        let p2 = point_from_str(p2)?;
+
+        let orientation = Line::determine_orientation(p1, p2);
+        if let Orientation::Diagonal = orientation {
+            if p1.0.abs_diff(p2.0) != p1.1.abs_diff(p2.1) {
+                return Err(Error::parse(format!("line is not at 45 degrees: {:?}", s)));
+            }
+        }
 
         Ok(Line {
             p1,
             p2,
-            orientation: Line::determine_orientation(p1, p2),
+            orientation,
         })
     }
 }
 
-fn point_from_str(s: &str) -> (usize, usize) {
-    let cords: Vec<usize> = s
-        .split(',')
-        .map(|pos| pos.trim().parse().expect("expected coordinates"))
-        .collect();
+fn point_from_str(s: &str) -> Result<(usize, usize)> {
+    let (x, y) = split_pair(s, ",")?;
 
-    (cords[0], cords[1])
+    Ok((x.trim().parse()?, y.trim().parse()?))
 }
 
-pub fn day_5_1<P: AsRef<Path>>(input_file: P) -> usize {
-    let lines: Vec<Line> = read_lines(input_file);
+pub fn part_1(input: &str) -> Result<usize> {
+    let lines: Vec<Line> = parse_lines(input)?;
 
     let mut diagram = init_diagram(&lines);
 
@@ -117,11 +124,11 @@ pub fn day_5_1<P: AsRef<Path>>(input_file: P) -> usize {
         l.apply_line(&mut diagram, true);
     }
 
-    count_more_than_2(&diagram)
+    Ok(count_more_than_2(&diagram))
 }
 
-pub fn day_5_2<P: AsRef<Path>>(input_file: P) -> usize {
-    let lines: Vec<Line> = read_lines(input_file);
+pub fn part_2(input: &str) -> Result<usize> {
+    let lines: Vec<Line> = parse_lines(input)?;
 
     let mut diagram = init_diagram(&lines);
 
@@ -129,7 +136,7 @@ pub fn day_5_2<P: AsRef<Path>>(input_file: P) -> usize {
         l.apply_line(&mut diagram, false);
     }
 
-    count_more_than_2(&diagram)
+    Ok(count_more_than_2(&diagram))
 }
 
 fn init_diagram(lines: &[Line]) -> Vec<Vec<usize>> {
@@ -155,10 +162,39 @@ fn count_more_than_2(diagram: &[Vec<usize>]) -> usize {
     })
 }
 
+pub fn day_5_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_5_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u16 {
+        5
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        let _: Vec<Line> = black_box(parse_lines(input)?);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::day05::{day_5_1, day_5_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day05::{part_2, Day05};
 
     const TEST_DATA: &str = "0,9 -> 5,9
 8,0 -> 0,8
@@ -171,11 +207,20 @@ mod test {
 0,0 -> 8,8
 5,5 -> 8,2";
 
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("5"),
+        part_2: Some("12"),
+    }];
+
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_05_01", TEST_DATA);
+        check_examples(&Day05, EXAMPLES);
 
-        assert_eq!(day_5_1(&file), 5);
-        assert_eq!(day_5_2(&file), 12);
+        let err = part_2("0,9 -> 5,9\n8,0 -> 0,7").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: line is not at 45 degrees: \"8,0 -> 0,7\""
+        );
     }
 }
@@ -0,0 +1,95 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_separated, read_input};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<u64> {
+    let fish_timers = read_lantern_fish_timers(input)?;
+    Ok(simulate_lantern_fish_population(fish_timers, 80))
+}
+
+pub fn part_2(input: &str) -> Result<u64> {
+    let fish_timers = read_lantern_fish_timers(input)?;
+    Ok(simulate_lantern_fish_population(fish_timers, 256))
+}
+
+fn read_lantern_fish_timers(input: &str) -> Result<Vec<u16>> {
+    let timers: Vec<u16> = parse_separated(input, ',').map_err(|e| e.at_line(1))?;
+    match timers.iter().find(|&&t| t > 8) {
+        Some(t) => Err(Error::parse(format!("timer out of range: {}", t)).at_line(1)),
+        None => Ok(timers),
+    }
+}
+
+fn simulate_lantern_fish_population(fish_timers: Vec<u16>, rounds: usize) -> u64 {
+    let mut fish_counts = [0; 9];
+
+    for ft in fish_timers {
+        fish_counts[ft as usize] += 1;
+    }
+
+    let mut new_fish = 0;
+    let mut maturing = 0;
+
+    for index in 0..rounds {
+        let birth_index = index % 7;
+
+        let matured_this_round = maturing;
+
+        maturing = new_fish;
+        new_fish = fish_counts[birth_index];
+
+        fish_counts[birth_index] += matured_this_round;
+    }
+
+    fish_counts.iter().sum::<u64>() + maturing + new_fish
+}
+
+pub fn day_6_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_6_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u16 {
+        6
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_lantern_fish_timers(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day06::Day06;
+
+    const TEST_DATA: &str = "3,4,3,1,2";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("5934"),
+        part_2: Some("26984457539"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day06, EXAMPLES);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_separated, read_input};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<i32> {
+    let mut crab_pos = read_crab_positions(input)?;
+
+    crab_pos.sort_unstable();
+    let median = *crab_pos
+        .get(crab_pos.len() / 2)
+        .ok_or_else(|| Error::parse("no crab positions"))?;
+
+    Ok(crab_pos
+        .iter()
+        .fold(0, |acc, pos| acc + (median - *pos).abs()))
+}
+
+pub fn part_2(input: &str) -> Result<i32> {
+    let crab_pos = read_crab_positions(input)?;
+
+    let mean = crab_pos.iter().sum::<i32>() as f64 / crab_pos.len() as f64;
+
+    // Simply rounding the mean did not seem to work in case of mean ending with .501
+    // so we ceil and floor and pick the best result from that.
+    Ok(calc_fuel_increasing(mean.ceil() as i32, &crab_pos)
+        .min(calc_fuel_increasing(mean.floor() as i32, &crab_pos)))
+}
+
+fn read_crab_positions(input: &str) -> Result<Vec<i32>> {
+    parse_separated(input, ',').map_err(|e| e.at_line(1))
+}
+
+fn calc_fuel_increasing(target_pos: i32, positions: &[i32]) -> i32 {
+    positions.iter().fold(0, |acc, pos| {
+        let n = (target_pos - *pos).abs();
+        acc + (n * (n + 1)) / 2
+    })
+}
+
+pub fn day_7_1<P: AsRef<Path>>(input_file: P) -> Result<i32> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_7_2<P: AsRef<Path>>(input_file: P) -> Result<i32> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u16 {
+        7
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_crab_positions(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day07::{part_1, part_2, Day07};
+
+    const TEST_DATA: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    const EXAMPLES: &[Example] = &[
+        Example {
+            input: TEST_DATA,
+            part_1: Some("37"),
+            part_2: Some("168"),
+        },
+        Example {
+            input: "5",
+            part_1: Some("0"),
+            part_2: Some("0"),
+        },
+    ];
+
+    #[test]
+    fn test() {
+        check_examples(&Day07, EXAMPLES);
+
+        assert!(part_1("").is_err());
+        assert!(part_2("").is_err());
+    }
+}
@@ -0,0 +1,367 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_lines, read_input, split_pair};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::iter::FromIterator;
+use std::path::Path;
+use std::str::FromStr;
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let display_data: Vec<DisplayData> = parse_lines(input)?;
+
+    // Digits 1, 4, 7 and 8 are the ones with unique number of signals.
+    Ok(display_data
+        .iter()
+        .flat_map(|dd| dd.output())
+        .filter(|enc_dig| enc_dig.to_digit().is_some())
+        .count())
+}
+
+// This solution is super ugly and I did not find a reasonable abstraction
+// in limited time I spent for solving this problem. I am sure it can be greatly improved.
+// Anyway, this works and I have some other stuff to do so hopefully
+// ancient gods of coding will not send their punishment on me...
+
+#[derive(PartialEq, Debug, Clone, Copy, Hash, Eq)]
+enum SignalPosition {
+    // Top,
+    // TopLeft,
+    TopRight,
+    Middle,
+    BottomLeft,
+    // BottomRight,
+    // Bottom,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DigitRepresentation(String);
+
+impl FromStr for DigitRepresentation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars: Vec<char> = s.chars().collect();
+        if let Some(pos) = chars.iter().position(|c| !('a'..='g').contains(c)) {
+            return Err(Error::unexpected_char(chars[pos]).at_column(pos + 1));
+        }
+        if chars.len() < 2 {
+            return Err(Error::parse(format!("too few signals: {:?}", s)));
+        }
+        chars.sort_unstable();
+
+        Ok(DigitRepresentation(String::from_iter(chars)))
+    }
+}
+
+impl DigitRepresentation {
+    fn to_digit(&self) -> Option<usize> {
+        match self.0.len() {
+            2 => Some(1),
+            4 => Some(4),
+            3 => Some(7),
+            7 => Some(8),
+            _ => None,
+        }
+    }
+
+    fn diff(&self, other: &DigitRepresentation) -> Vec<char> {
+        self.0
+            .clone()
+            .chars()
+            .filter(|ele| !other.0.contains(&ele.to_string()))
+            .collect()
+    }
+    fn common(&self, other: &DigitRepresentation) -> Vec<char> {
+        self.0
+            .clone()
+            .chars()
+            .filter(|ele| other.0.contains(&ele.to_string()))
+            .collect()
+    }
+}
+
+struct DisplayData {
+    signals: Vec<DigitRepresentation>,
+    digit_rep: Vec<Option<DigitRepresentation>>,
+    positions: HashMap<SignalPosition, char>,
+}
+
+impl DisplayData {
+    fn new(signals: Vec<DigitRepresentation>) -> DisplayData {
+        DisplayData {
+            signals,
+            digit_rep: vec![None; 10],
+            positions: HashMap::new(),
+        }
+    }
+
+    fn output(&self) -> &[DigitRepresentation] {
+        &self.signals[self.signals.len() - 4..]
+    }
+
+    fn digit(&self, digit: usize) -> Result<&DigitRepresentation> {
+        self.digit_rep[digit]
+            .as_ref()
+            .ok_or_else(|| Error::no_solution(format!("cannot determine digit {}", digit)))
+    }
+
+    fn position(&self, position: SignalPosition) -> Result<String> {
+        self.positions
+            .get(&position)
+            .map(|c| c.to_string())
+            .ok_or_else(|| Error::no_solution(format!("cannot determine {:?} signal", position)))
+    }
+
+    fn calc_value(&self) -> Result<usize> {
+        let mut value = 0;
+        for elem in self.output() {
+            value *= 10;
+            let digit = self
+                .digit_rep
+                .iter()
+                .position(|e| e.as_ref() == Some(elem))
+                .ok_or_else(|| Error::no_solution(format!("unknown digit: {}", elem.0)))?;
+            value += digit;
+        }
+        Ok(value)
+    }
+
+    fn determine_nine(
+        &self,
+        six_elem_signals: &[DigitRepresentation],
+    ) -> Result<DigitRepresentation> {
+        let fourth = self.digit(4)?;
+
+        // Find 9 by finding 6 len digit that has 4 common signals with 4
+        six_elem_signals
+            .iter()
+            .find(|e| e.common(fourth).len() == 4)
+            .cloned()
+            .ok_or_else(|| Error::no_solution("cannot determine digit 9"))
+    }
+
+    fn determine_middle_and_bottom_left(&mut self, zero_six: &[DigitRepresentation]) -> Result<()> {
+        if zero_six.len() != 2 {
+            return Err(Error::no_solution("cannot determine digits 0 and 6"));
+        }
+
+        // 8 and 6 different signal will be TopRight
+        // 8 and 0 different signal will be Middle
+        let diffs: Vec<Vec<char>> = vec![
+            self.digit(8)?.diff(&zero_six[0]),
+            self.digit(8)?.diff(&zero_six[1]),
+        ];
+        if diffs.iter().any(|diff| diff.len() != 1) {
+            return Err(Error::no_solution("cannot determine digits 0 and 6"));
+        }
+
+        // We determine which one is 0 and which one is 6.
+        let (num_zero_index, num_six_index) =
+            if !self.digit(1)?.0.contains(&diffs[0][0].to_string()) {
+                (0, 1)
+            } else {
+                (1, 0)
+            };
+
+        self.positions
+            .insert(SignalPosition::Middle, diffs[num_zero_index][0]);
+        self.digit_rep[0] = Some(zero_six[num_zero_index].clone());
+        self.positions
+            .insert(SignalPosition::TopRight, diffs[num_six_index][0]);
+        self.digit_rep[6] = Some(zero_six[num_six_index].clone());
+
+        // We now have 6 so we can find the signal that does not belong to 9 and therefore get BottomLeft.
+        let diff = self.digit(6)?.diff(self.digit(9)?);
+        let bottom_left = *diff
+            .first()
+            .ok_or_else(|| Error::no_solution("cannot determine BottomLeft signal"))?;
+        self.positions
+            .insert(SignalPosition::BottomLeft, bottom_left);
+        Ok(())
+    }
+
+    fn determine_five_len(&mut self, mut five_len: Vec<DigitRepresentation>) -> Result<()> {
+        let bottom_left = self.position(SignalPosition::BottomLeft)?;
+
+        // We get 2 by finding the only 5 signals digit that does contain BottomLeft signal.
+        let two = five_len
+            .iter()
+            .find(|e| e.0.contains(&bottom_left))
+            .ok_or_else(|| Error::no_solution("cannot determine digit 2"))?;
+
+        self.digit_rep[2] = Some(two.clone());
+
+        // We remove 2 from 5 signal digits.
+        five_len.retain(|e| !e.0.contains(&bottom_left));
+        if five_len.len() != 2 {
+            return Err(Error::no_solution("cannot determine digits 3 and 5"));
+        }
+
+        // Of two remaining we check which one contains TopRight signal - this will be 3, the other 5.
+        let (five_index, three_index) = if !five_len[0]
+            .0
+            .contains(&self.position(SignalPosition::TopRight)?)
+        {
+            (0, 1)
+        } else {
+            (1, 0)
+        };
+
+        self.digit_rep[5] = Some(five_len[five_index].clone());
+        self.digit_rep[3] = Some(five_len[three_index].clone());
+        Ok(())
+    }
+
+    fn decode(&mut self) -> Result<()> {
+        let signals = self.signals.clone();
+
+        // Those will be 0, 6 and 9
+        let mut six_len_ele_signals: Vec<DigitRepresentation> = vec![];
+
+        // Those will be 2, 3 and 5
+        let mut five_ele_signals: Vec<DigitRepresentation> = vec![];
+
+        for sig in &signals {
+            // We determine 1, 4, 7 and 8 right away
+            if let Some(dig) = sig.to_digit() {
+                self.digit_rep[dig] = Some(sig.clone());
+            } else if sig.0.len() == 6 && !six_len_ele_signals.contains(sig) {
+                six_len_ele_signals.push(sig.clone());
+            } else if sig.0.len() == 5 && !five_ele_signals.contains(sig) {
+                five_ele_signals.push(sig.clone());
+            }
+        }
+
+        let nine = self.determine_nine(&six_len_ele_signals)?;
+        six_len_ele_signals.retain(|e| e != &nine);
+        self.digit_rep[9] = Some(nine);
+
+        // This also determines 0 and 6
+        self.determine_middle_and_bottom_left(&six_len_ele_signals)?;
+
+        self.determine_five_len(five_ele_signals)
+    }
+}
+
+impl FromStr for DisplayData {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (patterns, output) = split_pair(s, "|")?;
+
+        let mut signals = vec![];
+        let mut column = 1;
+        for (part, expected) in [(patterns, 10), (output, 4)] {
+            let mut count = 0;
+            for enc_dig in part.split(' ') {
+                if !enc_dig.is_empty() {
+                    signals.push(enc_dig.parse().map_err(|e: Error| e.at_column(column))?);
+                    count += 1;
+                }
+                column += enc_dig.len() + 1;
+            }
+            if count != expected {
+                return Err(Error::parse(format!(
+                    "expected {} signal patterns, got {}",
+                    expected, count
+                )));
+            }
+        }
+
+        Ok(DisplayData::new(signals))
+    }
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let mut display_data: Vec<DisplayData> = parse_lines(input)?;
+
+    let mut sum = 0;
+    for dd in display_data.iter_mut() {
+        dd.decode()?;
+        sum += dd.calc_value()?;
+    }
+
+    Ok(sum)
+}
+
+pub fn day_8_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_8_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u16 {
+        8
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        let _: Vec<DisplayData> = black_box(parse_lines(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day08::{Day08, DisplayData};
+    use proptest::prelude::*;
+
+    const TEST_DATA: &str =
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("26"),
+        part_2: Some("61229"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day08, EXAMPLES);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(s in "\\PC*") {
+            let _ = s.parse::<DisplayData>();
+        }
+
+        #[test]
+        fn parse_never_panics_on_signals(s in "[a-g |]{0,80}") {
+            let _ = s.parse::<DisplayData>();
+        }
+
+        #[test]
+        fn parse_accepts_well_formed_lines(
+            patterns in prop::collection::vec("[a-g]{2,7}", 10),
+            output in prop::collection::vec("[a-g]{2,7}", 4),
+        ) {
+            let line = format!("{} | {}", patterns.join(" "), output.join(" "));
+            let data: DisplayData = line.parse().unwrap();
+            prop_assert_eq!(data.signals.len(), 14);
+        }
+    }
+}
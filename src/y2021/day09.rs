@@ -0,0 +1,109 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, Grid, Pos};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<u32> {
+    let land_heights = read_land_heights(input)?;
+
+    let sum = land_heights
+        .enumerate()
+        .filter(|&(pos, &val)| land_heights.neighbours(pos).all(|n| val < land_heights[n]))
+        .map(|(_, &val)| val + 1)
+        .sum();
+
+    Ok(sum)
+}
+
+pub fn part_2(input: &str) -> Result<u32> {
+    let land_heights = read_land_heights(input)?;
+
+    let mut visited = Grid::new(land_heights.rows(), land_heights.cols(), false);
+
+    let mut basin_sizes = vec![];
+    for pos in land_heights.positions() {
+        let size = check_basin_size(pos, &land_heights, &mut visited);
+        if size > 0 {
+            basin_sizes.push(size);
+        }
+    }
+    if basin_sizes.len() < 3 {
+        return Err(Error::no_solution("there are less than 3 basins"));
+    }
+
+    basin_sizes.sort_unstable();
+
+    Ok(basin_sizes.iter().skip(basin_sizes.len() - 3).product())
+}
+
+fn read_land_heights(input: &str) -> Result<Grid<u32>> {
+    Grid::parse_digits(input)
+}
+
+fn check_basin_size(pos: Pos, heights: &Grid<u32>, visited: &mut Grid<bool>) -> u32 {
+    if heights[pos] == 9 || visited[pos] {
+        return 0;
+    }
+
+    visited[pos] = true;
+
+    let mut sum = 1;
+    for next in heights.neighbours(pos) {
+        sum += check_basin_size(next, heights, visited);
+    }
+
+    sum
+}
+
+pub fn day_9_1<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_9_2<P: AsRef<Path>>(input_file: P) -> Result<u32> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u16 {
+        9
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_land_heights(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day09::Day09;
+
+    const TEST_DATA: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("15"),
+        part_2: Some("1134"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day09, EXAMPLES);
+    }
+}
@@ -1,19 +1,22 @@
-use crate::util::read_lines_raw;
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{lines, read_input};
+use std::hint::black_box;
 use std::path::Path;
 
-pub fn day_10_1<P: AsRef<Path>>(input_file: P) -> u64 {
-    let chunks_line = read_chunks_lines(input_file);
+pub fn part_1(input: &str) -> Result<u64> {
+    let chunks_line = read_chunks_lines(input)?;
 
-    chunks_line
+    Ok(chunks_line
         .iter()
         .fold(0, |acc, line| match score_line(line) {
             Score::Invalid(score) => acc + score,
             Score::Autocomplete(_) => acc,
-        })
+        }))
 }
 
-pub fn day_10_2<P: AsRef<Path>>(input_file: P) -> u64 {
-    let chunks_line = read_chunks_lines(input_file);
+pub fn part_2(input: &str) -> Result<u64> {
+    let chunks_line = read_chunks_lines(input)?;
 
     let mut scores: Vec<u64> = chunks_line
         .iter()
@@ -23,16 +26,27 @@ pub fn day_10_2<P: AsRef<Path>>(input_file: P) -> u64 {
         })
         .collect();
 
+    if scores.is_empty() {
+        return Err(Error::no_solution("there are no incomplete lines"));
+    }
     let mid_index = scores.len() / 2;
     let (_, middle, _) = scores.select_nth_unstable(mid_index);
 
-    *middle
+    Ok(*middle)
 }
 
-fn read_chunks_lines<P: AsRef<Path>>(input_file: P) -> Vec<Vec<char>> {
-    read_lines_raw(input_file)
-        .iter()
-        .map(|line| line.chars().collect())
+fn read_chunks_lines(input: &str) -> Result<Vec<Vec<char>>> {
+    lines(input)
+        .map(|line| {
+            line.text
+                .chars()
+                .enumerate()
+                .map(|(c, ch)| match ch {
+                    '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Ok(ch),
+                    _ => Err(Error::unexpected_char(ch).at(line.number, c + 1)),
+                })
+                .collect()
+        })
         .collect()
 }
 
@@ -98,10 +112,39 @@ fn score_opening(c: char) -> u64 {
     }
 }
 
+pub fn day_10_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_10_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u16 {
+        10
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_chunks_lines(input)?);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::day10::{day_10_1, day_10_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day10::Day10;
 
     const TEST_DATA: &str = "[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
@@ -114,11 +157,14 @@ mod test {
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]";
 
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("26397"),
+        part_2: Some("288957"),
+    }];
+
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_10_01", TEST_DATA);
-
-        assert_eq!(day_10_1(&file), 26397);
-        assert_eq!(day_10_2(&file), 288957);
+        check_examples(&Day10, EXAMPLES);
     }
 }
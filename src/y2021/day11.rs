@@ -0,0 +1,158 @@
+use crate::error::Result;
+use crate::solution::Solution;
+use crate::util::{read_input, Grid, Pos};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let mut octo_light = read_octo_energy(input)?;
+
+    for _i in 0..100 {
+        octo_light.start_step();
+    }
+
+    Ok(octo_light.total_flashes)
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let mut octo_light = read_octo_energy(input)?;
+
+    let octo_count = octo_light.energy.len();
+
+    let mut rounds = 0;
+    while octo_light.step_flashes < octo_count {
+        octo_light.start_step();
+        rounds += 1;
+    }
+    Ok(rounds)
+}
+
+fn read_octo_energy(input: &str) -> Result<OctoLight> {
+    let energy = Grid::parse_digits(input)?.map(|&val| (val, false));
+    Ok(OctoLight::new(energy))
+}
+
+struct OctoLight {
+    energy: Grid<(u32, bool)>,
+    total_flashes: usize,
+    step_flashes: usize,
+}
+
+impl OctoLight {
+    fn new(energy: Grid<(u32, bool)>) -> OctoLight {
+        OctoLight {
+            energy,
+            total_flashes: 0,
+            step_flashes: 0,
+        }
+    }
+
+    fn start_step(&mut self) {
+        self.step_flashes = 0;
+        let mut flashes = vec![];
+
+        for pos in self.energy.positions() {
+            let val = &mut self.energy[pos];
+            val.0 += 1;
+            if val.0 > 9 {
+                flashes.push(pos)
+            }
+        }
+
+        for pos in flashes {
+            self.flash(pos);
+        }
+
+        self.total_flashes += self.step_flashes;
+        self.finish_step()
+    }
+
+    fn flash(&mut self, pos: Pos) {
+        if self.energy[pos].1 {
+            return;
+        }
+
+        self.energy[pos] = (0, true);
+        self.step_flashes += 1;
+
+        let neighbour_pos: Vec<Pos> = self.energy.neighbours_with_diagonal(pos).collect();
+
+        for pos in neighbour_pos {
+            self.increase(pos);
+        }
+    }
+
+    fn increase(&mut self, pos: Pos) {
+        if self.energy[pos].1 {
+            return;
+        }
+
+        self.energy[pos].0 += 1;
+        if self.energy[pos].0 > 9 {
+            self.flash(pos);
+        }
+    }
+
+    fn finish_step(&mut self) {
+        for val in self.energy.iter_mut() {
+            val.1 = false;
+        }
+    }
+}
+
+pub fn day_11_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_11_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u16 {
+        11
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_octo_energy(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day11::Day11;
+
+    const TEST_DATA: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("1656"),
+        part_2: Some("195"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day11, EXAMPLES);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::error::{Error, Result};
+use crate::search::{self, SearchSpace};
+use crate::solution::Solution;
+use crate::trace;
+use crate::util::{lines, read_input, split_pair};
+use std::hint::black_box;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+enum CaveSize {
+    Small,
+    Big,
+}
+
+#[derive(Clone, Debug)]
+struct Cave {
+    name: String,
+    size: CaveSize,
+}
+
+impl FromStr for Cave {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((pos, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(Error::unexpected_char(c).at_column(pos + 1));
+        }
+        let start = s
+            .chars()
+            .next()
+            .ok_or_else(|| Error::parse("empty cave name"))?;
+        let size = if start.is_ascii_uppercase() {
+            CaveSize::Big
+        } else {
+            CaveSize::Small
+        };
+
+        Ok(Cave {
+            name: s.to_string(),
+            size,
+        })
+    }
+}
+
+pub fn part_1(input: &str) -> Result<u64> {
+    let caves = read_caves_data(input)?;
+
+    Ok(count_paths(&caves, caves.start(), false))
+}
+
+pub fn part_2(input: &str) -> Result<u64> {
+    let caves = read_caves_data(input)?;
+
+    Ok(count_paths(&caves, caves.start(), true))
+}
+
+struct CaveSystem {
+    caves: Vec<Cave>,
+    connections: Vec<Vec<usize>>,
+    start: usize,
+    end: Option<usize>,
+}
+
+impl CaveSystem {
+    // Small caves are tracked as bits of a visit.
+    const MAX_CAVES: usize = 64;
+
+    fn start(&self) -> Visit {
+        Visit {
+            cave: self.start,
+            visited: 1 << self.start,
+            visited_twice: false,
+        }
+    }
+
+    fn cave_index(&mut self, cave: Cave) -> Result<usize> {
+        if let Some(index) = self.caves.iter().position(|c| c.name == cave.name) {
+            return Ok(index);
+        }
+        if self.caves.len() == Self::MAX_CAVES {
+            return Err(Error::parse(format!("more than {} caves", Self::MAX_CAVES)));
+        }
+        self.caves.push(cave);
+        self.connections.push(vec![]);
+        Ok(self.caves.len() - 1)
+    }
+}
+
+/// Position on a path through the caves, along with small caves visited so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Visit {
+    cave: usize,
+    visited: u64,
+    visited_twice: bool,
+}
+
+/// Cave system explored by paths which may visit a single small cave twice.
+struct Paths<'a> {
+    caves: &'a CaveSystem,
+    allow_double: bool,
+}
+
+impl SearchSpace for Paths<'_> {
+    type State = Visit;
+
+    fn neighbours(&self, visit: &Visit) -> Vec<(Visit, u64)> {
+        self.caves.connections[visit.cave]
+            .iter()
+            .filter_map(|&cave| {
+                let mut next = Visit { cave, ..*visit };
+                if self.caves.caves[cave].size == CaveSize::Small {
+                    let bit = 1 << cave;
+                    if visit.visited & bit != 0 {
+                        if !self.allow_double || visit.visited_twice {
+                            return None;
+                        }
+                        next.visited_twice = true;
+                    }
+                    next.visited |= bit;
+                }
+                Some((next, 1))
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, visit: &Visit) -> bool {
+        Some(visit.cave) == self.caves.end
+    }
+}
+
+fn count_paths(caves: &CaveSystem, start: Visit, allow_double: bool) -> u64 {
+    let _span = trace::span("day12::count_paths");
+    search::count_paths(
+        &Paths {
+            caves,
+            allow_double,
+        },
+        start,
+    )
+}
+
+fn read_caves_data(input: &str) -> Result<CaveSystem> {
+    let mut caves = CaveSystem {
+        caves: vec![],
+        connections: vec![],
+        start: 0,
+        end: None,
+    };
+
+    for line in lines(input) {
+        let (from, to) = split_pair(line.text, "-").map_err(|e| e.at_line(line.number))?;
+        let from_cave = Cave::from_str(from).map_err(|e| e.at_line(line.number))?;
+        let to_cave = Cave::from_str(to).map_err(|e| e.at_line(line.number))?;
+        // Paths would be endless when moving between big caves.
+        if from_cave.size == CaveSize::Big && to_cave.size == CaveSize::Big {
+            return Err(Error::parse("big caves cannot be connected").at_line(line.number));
+        }
+
+        let from = caves
+            .cave_index(from_cave)
+            .map_err(|e| e.at_line(line.number))?;
+        let to = caves
+            .cave_index(to_cave)
+            .map_err(|e| e.at_line(line.number))?;
+        insert_connection(&mut caves, from, to);
+        insert_connection(&mut caves, to, from);
+    }
+
+    caves.start = caves
+        .caves
+        .iter()
+        .position(|cave| cave.name == "start")
+        .ok_or_else(|| Error::parse("missing start cave"))?;
+    caves.end = caves.caves.iter().position(|cave| cave.name == "end");
+
+    Ok(caves)
+}
+
+fn insert_connection(caves: &mut CaveSystem, from: usize, destination: usize) {
+    // Never treat start as a destination, and never leave the end.
+    if caves.caves[destination].name == "start" || caves.caves[from].name == "end" {
+        return;
+    }
+    caves.connections[from].push(destination);
+}
+
+pub fn day_12_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_12_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u16 {
+        12
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_caves_data(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day12::Day12;
+
+    const TEST_DATA_1: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+    const TEST_DATA_2: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+    const TEST_DATA_3: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+    const EXAMPLES: &[Example] = &[
+        Example {
+            input: TEST_DATA_1,
+            part_1: Some("10"),
+            part_2: Some("36"),
+        },
+        Example {
+            input: TEST_DATA_2,
+            part_1: Some("19"),
+            part_2: Some("103"),
+        },
+        Example {
+            input: TEST_DATA_3,
+            part_1: Some("226"),
+            part_2: Some("3509"),
+        },
+    ];
+
+    #[test]
+    fn test() {
+        check_examples(&Day12, EXAMPLES);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, split_block_pair, split_pair, strip_prefix};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::iter::FromIterator;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct Point(usize, usize);
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = split_pair(s, ",")?;
+        Ok(Point(x.parse()?, y.parse()?))
+    }
+}
+
+struct Pattern {
+    dots: HashSet<Point>,
+}
+
+impl Pattern {
+    fn new(dots: Vec<Point>) -> Pattern {
+        Pattern {
+            dots: HashSet::from_iter(dots),
+        }
+    }
+
+    fn fold_up(self, y: usize) -> Result<Pattern> {
+        Ok(Pattern {
+            dots: self
+                .dots
+                .into_iter()
+                .filter_map(|point| match point.1.cmp(&y) {
+                    Ordering::Less => Some(Ok(point)),
+                    Ordering::Equal => None,
+                    Ordering::Greater => Some(folded(y, point.1).map(|y| Point(point.0, y))),
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn fold_left(self, x: usize) -> Result<Pattern> {
+        Ok(Pattern {
+            dots: self
+                .dots
+                .into_iter()
+                .filter_map(|point| match point.0.cmp(&x) {
+                    Ordering::Less => Some(Ok(point)),
+                    Ordering::Equal => None,
+                    Ordering::Greater => Some(folded(x, point.0).map(|x| Point(x, point.1))),
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn fold(self, fold: &Fold) -> Result<Pattern> {
+        match fold {
+            Fold::Up(y) => self.fold_up(*y),
+            Fold::Left(x) => self.fold_left(*x),
+        }
+    }
+}
+
+fn folded(line: usize, pos: usize) -> Result<usize> {
+    (2 * line).checked_sub(pos).ok_or_else(|| {
+        Error::no_solution(format!(
+            "dot at {} does not fit after folding at {}",
+            pos, line
+        ))
+    })
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (max_x, max_y) = self.dots.iter().fold((0, 0), |(max_x, max_y), point| {
+            (max_x.max(point.0), max_y.max(point.1))
+        });
+
+        let mut v = vec![vec!['.'; max_x + 1]; max_y + 1];
+        for d in &self.dots {
+            v[d.1][d.0] = '#';
+        }
+        let mut out = String::from("");
+        for row in &v {
+            out.push('\n');
+            out.push_str(&row.iter().collect::<String>());
+        }
+        f.write_str(&out)
+    }
+}
+
+enum Fold {
+    Up(usize),
+    Left(usize),
+}
+
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let fold_str = strip_prefix(s, "fold along ")?;
+        let (direction, val) = split_pair(fold_str, "=")?;
+
+        match direction {
+            "x" => Ok(Fold::Left(val.parse()?)),
+            "y" => Ok(Fold::Up(val.parse()?)),
+            other => Err(Error::parse(format!("unexpected direction: {:?}", other))),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let (mut pattern, folds) = read_data(input)?;
+
+    for f in folds.iter().take(1) {
+        pattern = pattern.fold(f)?;
+    }
+
+    Ok(pattern.dots.len())
+}
+
+pub fn part_2(input: &str) -> Result<String> {
+    let (mut pattern, folds) = read_data(input)?;
+
+    for f in &folds {
+        pattern = pattern.fold(f)?;
+    }
+
+    Ok(pattern.to_string())
+}
+
+fn read_data(input: &str) -> Result<(Pattern, Vec<Fold>)> {
+    let (points, folds) = split_block_pair(input)?;
+
+    let dots: Vec<Point> = points.parse_lines()?;
+    let folds: Vec<Fold> = folds.parse_lines()?;
+
+    Ok((Pattern::new(dots), folds))
+}
+
+pub fn day_13_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_13_2<P: AsRef<Path>>(input_file: P) -> Result<String> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u16 {
+        13
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_data(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day13::{part_1, Day13};
+
+    const TEST_DATA: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    const DAY_2_OUT: &str = "
+#####
+#...#
+#...#
+#...#
+#####";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("17"),
+        part_2: Some(DAY_2_OUT),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day13, EXAMPLES);
+
+        let err = part_1("6,10\n0,14\n\nfold along y=7\nfold along z=5").unwrap_err();
+        assert_eq!(err.to_string(), "line 5: unexpected direction: \"z\"");
+    }
+}
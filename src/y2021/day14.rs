@@ -0,0 +1,228 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, split_block_pair, split_pair};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<u64> {
+    find_most_least_frequent_diff(input, 10)
+}
+
+pub fn part_2(input: &str) -> Result<u64> {
+    find_most_least_frequent_diff(input, 40)
+}
+
+fn find_most_least_frequent_diff(input: &str, rounds: usize) -> Result<u64> {
+    let (template, inserts) = read_data(input)?;
+
+    let mut pair_map = HashMap::new();
+
+    // Each pair after insert result in 2 pairs.
+    // As there is finite number of pairs, create mapping for each.
+    for i in 0..template.len() - 1 {
+        make_pair_map(&template[i..i + 2], &inserts, &mut pair_map)?;
+    }
+
+    let mut counts: [u64; 26] = [0; 26];
+
+    // Mem maps round + pair to resulting counts.
+    // As such combination is unique and will repeat multiple times
+    // it is used to reduce number of recursive calls.
+    let mut mem: HashMap<(usize, String), [u64; 26]> = HashMap::new();
+
+    // Count inserted elements for each pair recursively for a given amount of rounds.
+    for i in 0..template.len() - 1 {
+        let c = count_elements(&template[i..i + 2], rounds, &pair_map, &inserts, &mut mem)?;
+        merge_counts(&mut counts, &c);
+    }
+
+    // Add characters of initial template to counts.
+    for c in template.chars() {
+        counts[c as usize - 65] += 1;
+    }
+
+    let max = counts.iter().max().unwrap_or(&0);
+    let min = counts.iter().filter(|x| **x > 0).min().unwrap_or(&0);
+
+    Ok(max - min)
+}
+
+fn count_elements(
+    pair: &str,
+    rounds: usize,
+    map: &HashMap<String, (String, String)>,
+    inserts: &HashMap<String, char>,
+    mem: &mut HashMap<(usize, String), [u64; 26]>,
+) -> Result<[u64; 26]> {
+    if rounds == 0 {
+        return Ok([0; 26]);
+    }
+
+    match mem.get(&(rounds, pair.to_string())) {
+        None => {}
+        Some(counts) => return Ok(*counts),
+    }
+
+    let mut counts = [0; 26];
+
+    let insert = find_insert(pair, inserts)?;
+    counts[insert as usize - 65] += 1;
+
+    let (p1, p2) = map
+        .get(pair)
+        .ok_or_else(|| Error::no_solution(format!("no mapping for pair {}", pair)))?;
+
+    let c1 = count_elements(p1, rounds - 1, map, inserts, mem)?;
+    let c2 = count_elements(p2, rounds - 1, map, inserts, mem)?;
+
+    merge_counts(&mut counts, &c1);
+    merge_counts(&mut counts, &c2);
+
+    mem.insert((rounds, pair.to_string()), counts);
+
+    Ok(counts)
+}
+
+fn find_insert(pair: &str, inserts: &HashMap<String, char>) -> Result<char> {
+    inserts
+        .get(pair)
+        .copied()
+        .ok_or_else(|| Error::no_solution(format!("no insertion rule for pair {}", pair)))
+}
+
+fn merge_counts(a: &mut [u64; 26], b: &[u64; 26]) {
+    for (i, c) in b.iter().enumerate() {
+        a[i] += *c;
+    }
+}
+
+fn make_pair_map(
+    pair: &str,
+    inserts: &HashMap<String, char>,
+    pair_map: &mut HashMap<String, (String, String)>,
+) -> Result<()> {
+    if pair_map.get(pair).is_none() {
+        let insert_char = find_insert(pair, inserts)?;
+
+        let p1 = format!("{}{}", &pair[..1], insert_char);
+        let p2 = format!("{}{}", insert_char, &pair[1..]);
+
+        pair_map.insert(pair.to_string(), (p1.clone(), p2.clone()));
+        make_pair_map(&p1, inserts, pair_map)?;
+        make_pair_map(&p2, inserts, pair_map)?;
+    }
+    Ok(())
+}
+
+fn read_data(input: &str) -> Result<(String, HashMap<String, char>)> {
+    let (template, insertions) = split_block_pair(input)?;
+    let template = match template.lines.as_slice() {
+        [line] => line.text,
+        _ => {
+            let extra_line = template.first_line() + 1;
+            return Err(Error::parse("template must be a single line").at_line(extra_line));
+        }
+    };
+
+    check_elements(template).map_err(|e| e.at_line(1))?;
+    if template.len() < 2 {
+        return Err(Error::parse("template is too short").at_line(1));
+    }
+
+    let insertions: HashMap<String, char> = insertions
+        .lines
+        .iter()
+        .map(|line| {
+            let rule = || {
+                let (k, v) = split_pair(line.text, " -> ")?;
+                check_elements(k)?;
+                check_elements(v)?;
+                Ok((k, v))
+            };
+            let (k, v) = rule().map_err(|e: Error| e.at_line(line.number))?;
+            match (k.len(), v.len()) {
+                (2, 1) => Ok((k.to_string(), v.as_bytes()[0] as char)),
+                _ => Err(Error::parse(format!(
+                    "invalid insertion rule: {:?}",
+                    line.text
+                ))),
+            }
+            .map_err(|e| e.at_line(line.number))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((template.to_string(), insertions))
+}
+
+fn check_elements(s: &str) -> Result<()> {
+    match s.char_indices().find(|(_, c)| !c.is_ascii_uppercase()) {
+        Some((pos, c)) => Err(Error::unexpected_char(c).at_column(pos + 1)),
+        None => Ok(()),
+    }
+}
+
+pub fn day_14_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_14_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u16 {
+        14
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_data(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day14::Day14;
+
+    const TEST_DATA: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("1588"),
+        part_2: Some("2188189693529"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day14, EXAMPLES);
+    }
+}
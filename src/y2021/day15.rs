@@ -0,0 +1,156 @@
+use crate::error::{Error, Result};
+use crate::search::{a_star, SearchSpace};
+use crate::solution::Solution;
+use crate::util::{read_input, Grid, Pos};
+use std::hint::black_box;
+use std::path::Path;
+
+pub fn part_1(input: &str) -> Result<u64> {
+    let risk_levels = read_risk_levels(input)?;
+
+    lowest_total_risk(Cavern::new(risk_levels))
+}
+
+pub fn part_2(input: &str) -> Result<u64> {
+    let risk_levels = read_risk_levels(input)?;
+    let risk_levels = transform_x5(risk_levels);
+
+    lowest_total_risk(Cavern::new(risk_levels))
+}
+
+struct Cavern {
+    risk_levels: Grid<u64>,
+    end_pos: Pos,
+}
+
+impl Cavern {
+    fn new(risk_levels: Grid<u64>) -> Cavern {
+        let end_pos = (risk_levels.rows() - 1, risk_levels.cols() - 1);
+        Cavern {
+            risk_levels,
+            end_pos,
+        }
+    }
+}
+
+impl SearchSpace for Cavern {
+    type State = Pos;
+
+    fn neighbours(&self, &pos: &Pos) -> Vec<(Pos, u64)> {
+        self.risk_levels
+            .neighbours(pos)
+            .map(|next_pos| (next_pos, self.risk_levels[next_pos]))
+            .collect()
+    }
+
+    fn is_goal(&self, &pos: &Pos) -> bool {
+        pos == self.end_pos
+    }
+
+    // Every step adds at least 1 risk.
+    fn heuristic(&self, &(r, c): &Pos) -> u64 {
+        ((self.end_pos.0 - r) + (self.end_pos.1 - c)) as u64
+    }
+}
+
+fn lowest_total_risk(cavern: Cavern) -> Result<u64> {
+    a_star(&cavern, (0, 0))
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::no_solution("failed to find path to the end position"))
+}
+
+/// Risk levels are digits from 1 to 9, the heuristic relies on every step costing at least 1.
+fn read_risk_levels(input: &str) -> Result<Grid<u64>> {
+    Grid::parse(input, |ch| match ch.to_digit(10) {
+        Some(0) => Err(Error::parse("risk level must be at least 1")),
+        Some(d) => Ok(d.into()),
+        None => Err(Error::unexpected_char(ch)),
+    })
+}
+
+/// Repeats the map 5 times in both directions, risk grows by 1 with every
+/// repetition and wraps from 9 back to 1.
+fn transform_x5(risk_levels: Grid<u64>) -> Grid<u64> {
+    let (rows, cols) = (risk_levels.rows(), risk_levels.cols());
+
+    Grid::from_fn(rows * 5, cols * 5, |(r, c)| {
+        let risk = risk_levels[(r % rows, c % cols)] + (r / rows + c / cols) as u64;
+        (risk - 1) % 9 + 1
+    })
+}
+
+pub fn day_15_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_15_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u16 {
+        15
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_risk_levels(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::solution::{check_examples, Example, Solution};
+    use crate::y2021::day15::Day15;
+
+    const TEST_DATA: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    const EXAMPLES: &[Example] = &[
+        Example {
+            input: TEST_DATA,
+            part_1: Some("40"),
+            part_2: Some("315"),
+        },
+        // Risk wraps from 9 back to 1 in the repeated tiles.
+        Example {
+            input: "9",
+            part_1: Some("0"),
+            part_2: Some("36"),
+        },
+    ];
+
+    #[test]
+    fn test() {
+        check_examples(&Day15, EXAMPLES);
+
+        assert_eq!(
+            Day15.parse("19\n20"),
+            Err(Error::parse("risk level must be at least 1").at(2, 2))
+        );
+        assert_eq!(Day15.parse("1a"), Err(Error::unexpected_char('a').at(1, 2)));
+        assert_eq!(
+            Day15.parse(""),
+            Err(Error::parse("grid is empty").at_line(1))
+        );
+    }
+}
@@ -0,0 +1,1362 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use bitvec::prelude::*;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::hint::black_box;
+use std::io::{BufReader, Bytes, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+const VERSION_BITS: usize = 3;
+const TOTAL_BITS_BITS: usize = 15;
+const PACKET_COUNT_BITS: usize = 11;
+/// Packets are processed recursively, so neither decoding nor compiling nests them
+/// deeper than this below the outermost one.
+const MAX_DEPTH: usize = 100;
+
+/// Packet of the BITS transmission, parsed from hex and encoded back with [`Packet::to_hex`],
+/// displayed as an S-expression such as `(+ (* 2 3) (min 7 8))`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub version: usize,
+    pub content: PacketContent,
+    pub packet_type: PacketType,
+}
+
+impl Packet {
+    pub fn literal(version: usize, value: usize) -> Packet {
+        Packet {
+            version,
+            content: PacketContent::Literal(value),
+            packet_type: PacketType::Literal,
+        }
+    }
+
+    /// Operator packet counting its sub-packets, which takes fewer bits than their
+    /// total length, unless there are too many of them to be counted.
+    pub fn operator(version: usize, packet_type: PacketType, packets: Vec<Packet>) -> Packet {
+        let length_type = if packets.len() < 1 << PACKET_COUNT_BITS {
+            LengthType::PacketCount
+        } else {
+            LengthType::TotalBits
+        };
+        Packet {
+            version,
+            content: PacketContent::SubPackets {
+                length_type,
+                packets,
+            },
+            packet_type,
+        }
+    }
+
+    /// Levels of packets below this one.
+    fn depth(&self) -> usize {
+        match &self.content {
+            PacketContent::Literal(_) => 0,
+            PacketContent::SubPackets { packets, .. } => {
+                1 + packets.iter().map(Packet::depth).max().unwrap_or_default()
+            }
+        }
+    }
+
+    pub fn sum_versions(&self) -> usize {
+        match &self.content {
+            PacketContent::Literal(_) => self.version,
+            PacketContent::SubPackets { packets, .. } => {
+                self.version + packets.iter().map(|sp| sp.sum_versions()).sum::<usize>()
+            }
+        }
+    }
+
+    pub fn eval(&self) -> Result<usize> {
+        match &self.content {
+            PacketContent::Literal(val) => Ok(*val),
+            PacketContent::SubPackets { packets, .. } => {
+                let sub_packets_values: Vec<usize> =
+                    packets.iter().map(|sp| sp.eval()).collect::<Result<_>>()?;
+
+                let value = match (&self.packet_type, sub_packets_values.as_slice()) {
+                    (PacketType::Sum, values) => values
+                        .iter()
+                        .try_fold(0usize, |sum, &value| sum.checked_add(value)),
+                    (PacketType::Product, values) => values
+                        .iter()
+                        .try_fold(1usize, |product, &value| product.checked_mul(value)),
+                    (PacketType::Min, [first, values @ ..]) => {
+                        Some(*values.iter().fold(first, Ord::min))
+                    }
+                    (PacketType::Max, [first, values @ ..]) => {
+                        Some(*values.iter().fold(first, Ord::max))
+                    }
+                    (PacketType::Greater, [a, b]) => Some((a > b).into()),
+                    (PacketType::Less, [a, b]) => Some((a < b).into()),
+                    (PacketType::Equal, [a, b]) => Some((a == b).into()),
+                    (packet_type, values) => {
+                        return Err(Error::parse(format!(
+                            "{:?} packet cannot have {} sub-packets",
+                            packet_type,
+                            values.len()
+                        )))
+                    }
+                };
+                value.ok_or_else(|| {
+                    Error::no_solution(format!("value of {:?} packet overflows", self.packet_type))
+                })
+            }
+        }
+    }
+
+    /// Encodes the packet as a transmission padded with zeros to whole bytes.
+    pub fn to_hex(&self) -> Result<String> {
+        let mut bits = self.to_bits()?;
+        bits.set_uninitialized(false);
+        Ok(bits
+            .into_vec()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect())
+    }
+
+    pub fn to_bits(&self) -> Result<BitVec<Msb0, u8>> {
+        let mut bits = BitVec::new();
+        self.encode(&mut bits)?;
+        Ok(bits)
+    }
+
+    fn encode(&self, bits: &mut BitVec<Msb0, u8>) -> Result<()> {
+        push_num(bits, self.version, VERSION_BITS, "version")?;
+        push_num(bits, self.packet_type.id().into(), 3, "type")?;
+
+        match (&self.content, self.packet_type) {
+            (PacketContent::Literal(value), PacketType::Literal) => {
+                let groups = ((usize::BITS - value.leading_zeros()) as usize).div_ceil(4);
+                for group in (0..groups.max(1)).rev() {
+                    bits.push(group > 0);
+                    push_num(bits, (value >> (4 * group)) & 0xF, 4, "literal")?;
+                }
+            }
+            (
+                PacketContent::SubPackets {
+                    length_type,
+                    packets,
+                },
+                packet_type,
+            ) if packet_type != PacketType::Literal => {
+                if !packet_type.accepts(packets.len()) {
+                    return Err(Error::Encode(format!(
+                        "{:?} packet cannot have {} sub-packets",
+                        packet_type,
+                        packets.len()
+                    )));
+                }
+                let mut sub_bits = BitVec::new();
+                for packet in packets {
+                    packet.encode(&mut sub_bits)?;
+                }
+                match length_type {
+                    LengthType::TotalBits => {
+                        bits.push(false);
+                        push_num(bits, sub_bits.len(), TOTAL_BITS_BITS, "length in bits")?;
+                    }
+                    LengthType::PacketCount => {
+                        bits.push(true);
+                        push_num(
+                            bits,
+                            packets.len(),
+                            PACKET_COUNT_BITS,
+                            "number of sub-packets",
+                        )?;
+                    }
+                }
+                bits.extend_from_bitslice(&sub_bits);
+            }
+            (_, packet_type) => {
+                return Err(Error::Encode(format!(
+                    "content does not match {:?} packet",
+                    packet_type
+                )))
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Packet {
+    /// Decodes the outermost packet of a hex transmission, the bits following it
+    /// must be zeros.
+    pub fn decode(hex: &str) -> DecodeResult<Packet> {
+        read_transmission(hex.as_bytes())
+    }
+
+    /// Decodes the transmission as it is read, see [`Packet::decode`].
+    pub fn read<R: Read>(reader: R) -> DecodeResult<Packet> {
+        read_transmission(reader)
+    }
+}
+
+impl FromStr for Packet {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> DecodeResult<Self> {
+        Packet::decode(s)
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.content {
+            PacketContent::Literal(value) => write!(f, "{}", value),
+            PacketContent::SubPackets { packets, .. } => {
+                write!(f, "({}", self.packet_type.symbol())?;
+                for packet in packets {
+                    write!(f, " {}", packet)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn push_num(bits: &mut BitVec<Msb0, u8>, value: usize, width: usize, name: &str) -> Result<()> {
+    if width < usize::BITS as usize && value >> width != 0 {
+        return Err(Error::Encode(format!(
+            "{} {} does not fit in {} bits",
+            name, value, width
+        )));
+    }
+    bits.extend((0..width).rev().map(|i| (value >> i) & 1 == 1));
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketContent {
+    Literal(usize),
+    SubPackets {
+        length_type: LengthType,
+        packets: Vec<Packet>,
+    },
+}
+
+/// How the sub-packets of an operator are delimited, the transmission can use either.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LengthType {
+    /// Type 0, total length of the sub-packets in bits.
+    TotalBits,
+    /// Type 1, number of the sub-packets.
+    PacketCount,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PacketType {
+    Literal,
+    Sum,
+    Product,
+    Min,
+    Max,
+    Greater,
+    Less,
+    Equal,
+}
+
+impl PacketType {
+    pub fn id(&self) -> u8 {
+        match self {
+            PacketType::Sum => 0,
+            PacketType::Product => 1,
+            PacketType::Min => 2,
+            PacketType::Max => 3,
+            PacketType::Literal => 4,
+            PacketType::Greater => 5,
+            PacketType::Less => 6,
+            PacketType::Equal => 7,
+        }
+    }
+
+    /// Whether the operation is defined for that many sub-packets.
+    fn accepts(&self, count: usize) -> bool {
+        match self {
+            PacketType::Literal => false,
+            PacketType::Sum | PacketType::Product | PacketType::Min | PacketType::Max => count > 0,
+            PacketType::Greater | PacketType::Less | PacketType::Equal => count == 2,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            PacketType::Literal => "literal",
+            PacketType::Sum => "+",
+            PacketType::Product => "*",
+            PacketType::Min => "min",
+            PacketType::Max => "max",
+            PacketType::Greater => ">",
+            PacketType::Less => "<",
+            PacketType::Equal => "=",
+        }
+    }
+}
+
+impl TryFrom<u8> for PacketType {
+    type Error = Error;
+
+    fn try_from(num: u8) -> Result<Self> {
+        match num {
+            0 => Ok(PacketType::Sum),
+            1 => Ok(PacketType::Product),
+            2 => Ok(PacketType::Min),
+            3 => Ok(PacketType::Max),
+            4 => Ok(PacketType::Literal),
+            5 => Ok(PacketType::Greater),
+            6 => Ok(PacketType::Less),
+            7 => Ok(PacketType::Equal),
+            _ => Err(Error::parse(format!(
+                "unexpected operation number: {}",
+                num
+            ))),
+        }
+    }
+}
+
+/// Error of decoding a transmission, located at the offset of the offending bit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub kind: DecodeErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    UnexpectedChar(char),
+    OddHexDigits,
+    /// The transmission ends in the middle of a packet.
+    Truncated,
+    /// Bits following the outermost packet are not all zeros.
+    NonZeroPadding,
+    UnknownType(u8),
+    /// The operation is not defined for that many sub-packets.
+    OperandCount {
+        packet_type: PacketType,
+        count: usize,
+    },
+    /// Literal value does not fit in `usize`.
+    LiteralOverflow,
+    /// Sub-packets are nested deeper than the decoder recurses.
+    NestedTooDeeply,
+    /// Reading the transmission failed.
+    Read(String),
+}
+
+pub type DecodeResult<T> = std::result::Result<T, DecodeError>;
+
+impl DecodeError {
+    fn new(offset: usize, kind: DecodeErrorKind) -> DecodeError {
+        DecodeError { offset, kind }
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bit {}: ", self.offset)?;
+        match &self.kind {
+            DecodeErrorKind::UnexpectedChar(c) => write!(f, "unexpected character: {:?}", c),
+            DecodeErrorKind::OddHexDigits => write!(f, "odd number of hex digits"),
+            DecodeErrorKind::Truncated => write!(f, "unexpected end of packet"),
+            DecodeErrorKind::NonZeroPadding => write!(f, "padding after the packet is not zero"),
+            DecodeErrorKind::UnknownType(id) => write!(f, "unexpected operation number: {}", id),
+            DecodeErrorKind::OperandCount { packet_type, count } => write!(
+                f,
+                "{:?} packet cannot have {} sub-packets",
+                packet_type, count
+            ),
+            DecodeErrorKind::LiteralOverflow => write!(f, "literal value is too large"),
+            DecodeErrorKind::NestedTooDeeply => write!(f, "packets nested too deeply"),
+            DecodeErrorKind::Read(message) => write!(f, "failed to read: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Errors in the text of the transmission point at the hex digit instead of the bit.
+impl From<DecodeError> for Error {
+    fn from(err: DecodeError) -> Self {
+        let column = err.offset / 4 + 1;
+        match err.kind {
+            DecodeErrorKind::UnexpectedChar(c) => Error::unexpected_char(c).at(1, column),
+            DecodeErrorKind::OddHexDigits => Error::parse("odd number of hex digits").at(1, column),
+            DecodeErrorKind::Read(message) => Error::Io(message),
+            _ => Error::parse(err.to_string()),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let top_packet = parse_top_packet(input)?;
+    Ok(top_packet.sum_versions())
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let top_packet = parse_top_packet(input)?;
+    top_packet.eval()
+}
+
+fn parse_top_packet(input: &str) -> Result<Packet> {
+    Ok(Packet::decode(input)?)
+}
+
+/// Decodes top-level packets of hex transmissions as they are read, without holding
+/// more than a hex digit of the input at once.
+///
+/// Every line is a transmission, which may contain multiple packets one after another.
+/// The bits following the last one must be zeros. An error skips the rest of the
+/// transmission it occurred in.
+pub struct PacketReader<R: Read> {
+    bits: HexBits<R>,
+    in_transmission: bool,
+    done: bool,
+}
+
+impl<R: Read> PacketReader<R> {
+    pub fn new(reader: R) -> PacketReader<R> {
+        PacketReader {
+            bits: HexBits::new(reader),
+            in_transmission: false,
+            done: false,
+        }
+    }
+
+    /// Line of the transmission the last packet or error was read from, starting at 1.
+    pub fn line(&self) -> usize {
+        self.bits.line
+    }
+
+    fn next_packet(&mut self) -> DecodeResult<Option<Packet>> {
+        let follows = self.in_transmission && self.bits.skip_padding()?;
+        if !follows && !self.bits.next_transmission()? {
+            return Ok(None);
+        }
+        self.in_transmission = true;
+        read_packet(&mut self.bits, 0).map(Some)
+    }
+}
+
+impl<R: Read> Iterator for PacketReader<R> {
+    type Item = DecodeResult<Packet>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_packet() {
+            Ok(Some(packet)) => Some(Ok(packet)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.in_transmission = false;
+                self.done = matches!(err.kind, DecodeErrorKind::Read(_));
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Decodes a single packet followed by zeros, and only whitespace after them.
+fn read_transmission<R: Read>(reader: R) -> DecodeResult<Packet> {
+    let mut bits = HexBits::new(reader);
+    if !bits.next_transmission()? {
+        return Err(DecodeError::new(0, DecodeErrorKind::Truncated));
+    }
+    let packet = read_packet(&mut bits, 0)?;
+    if bits.skip_padding()? {
+        let one = bits.offset + bits.replay_zeros;
+        return Err(DecodeError::new(one, DecodeErrorKind::NonZeroPadding));
+    }
+
+    let (end, terminator) = (bits.offset, bits.terminator);
+    if bits.next_transmission()? {
+        let c = terminator.map_or('\n', char::from);
+        return Err(DecodeError::new(end, DecodeErrorKind::UnexpectedChar(c)));
+    }
+    Ok(packet)
+}
+
+fn read_packet<R: Read>(bits: &mut HexBits<R>, depth: usize) -> DecodeResult<Packet> {
+    let start = bits.offset;
+    if depth > MAX_DEPTH {
+        return Err(DecodeError::new(start, DecodeErrorKind::NestedTooDeeply));
+    }
+    let version = bits.take(3)?;
+
+    let packet_type_id = bits.take(3)? as u8;
+    let packet_type = PacketType::try_from(packet_type_id)
+        .map_err(|_| DecodeError::new(start + 3, DecodeErrorKind::UnknownType(packet_type_id)))?;
+
+    let content = match packet_type {
+        PacketType::Literal => read_literal(bits)?,
+        _ => read_operator(bits, packet_type, depth)?,
+    };
+
+    Ok(Packet {
+        version,
+        content,
+        packet_type,
+    })
+}
+
+fn read_literal<R: Read>(bits: &mut HexBits<R>) -> DecodeResult<PacketContent> {
+    let mut value: usize = 0;
+    loop {
+        let start = bits.offset;
+        let more = bits.bit()?;
+        let group = bits.take(4)?;
+        if value.leading_zeros() < 4 {
+            return Err(DecodeError::new(start, DecodeErrorKind::LiteralOverflow));
+        }
+        value = (value << 4) | group;
+
+        if !more {
+            break;
+        }
+    }
+
+    Ok(PacketContent::Literal(value))
+}
+
+fn read_operator<R: Read>(
+    bits: &mut HexBits<R>,
+    packet_type: PacketType,
+    depth: usize,
+) -> DecodeResult<PacketContent> {
+    let offset = bits.offset;
+    let length_type = if bits.bit()? {
+        LengthType::PacketCount
+    } else {
+        LengthType::TotalBits
+    };
+    let packets = match length_type {
+        LengthType::PacketCount => {
+            let packets_num = bits.take(PACKET_COUNT_BITS)?;
+            (0..packets_num)
+                .map(|_| read_packet(bits, depth + 1))
+                .collect::<DecodeResult<Vec<Packet>>>()?
+        }
+        LengthType::TotalBits => {
+            let to_process = bits.take(TOTAL_BITS_BITS)?;
+            let end = bits.offset + to_process;
+            // Sub-packets must not run past their length, nor past the enclosing one.
+            let outer_limit = bits.limit;
+            bits.limit = end.min(outer_limit);
+            let mut packets = vec![];
+            while bits.offset < end {
+                packets.push(read_packet(bits, depth + 1)?);
+            }
+            bits.limit = outer_limit;
+            packets
+        }
+    };
+
+    if !packet_type.accepts(packets.len()) {
+        return Err(DecodeError::new(
+            offset,
+            DecodeErrorKind::OperandCount {
+                packet_type,
+                count: packets.len(),
+            },
+        ));
+    }
+    Ok(PacketContent::SubPackets {
+        length_type,
+        packets,
+    })
+}
+
+/// Bits of hex transmissions separated by whitespace, read one digit at a time.
+struct HexBits<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    peeked: Option<u8>,
+    newlines: usize,
+    /// Line of the current transmission (1-based).
+    line: usize,
+    /// Bits taken from the current transmission.
+    offset: usize,
+    /// Bits past it belong to an enclosing packet, or to no packet.
+    limit: usize,
+    digits: usize,
+    digit: u8,
+    digit_bits: u8,
+    /// Zeros followed by a one which were read ahead and are taken again.
+    replay_zeros: usize,
+    replay_one: bool,
+    ended: bool,
+    /// Character which ended the transmission, `None` at the end of input.
+    terminator: Option<u8>,
+}
+
+impl<R: Read> HexBits<R> {
+    fn new(reader: R) -> HexBits<R> {
+        HexBits {
+            bytes: BufReader::new(reader).bytes(),
+            peeked: None,
+            newlines: 0,
+            line: 1,
+            offset: 0,
+            limit: usize::MAX,
+            digits: 0,
+            digit: 0,
+            digit_bits: 0,
+            replay_zeros: 0,
+            replay_one: false,
+            ended: true,
+            terminator: None,
+        }
+    }
+
+    fn take(&mut self, width: usize) -> DecodeResult<usize> {
+        (0..width).try_fold(0, |acc, _| Ok((acc << 1) | usize::from(self.bit()?)))
+    }
+
+    fn bit(&mut self) -> DecodeResult<bool> {
+        if self.offset >= self.limit {
+            return Err(DecodeError::new(self.limit, DecodeErrorKind::Truncated));
+        }
+        let bit = if self.replay_zeros > 0 {
+            self.replay_zeros -= 1;
+            false
+        } else if self.replay_one {
+            self.replay_one = false;
+            true
+        } else {
+            if self.digit_bits == 0 {
+                self.next_digit()?;
+            }
+            self.digit_bits -= 1;
+            (self.digit >> self.digit_bits) & 1 == 1
+        };
+        self.offset += 1;
+        Ok(bit)
+    }
+
+    fn next_digit(&mut self) -> DecodeResult<()> {
+        if !self.ended {
+            match self.read_byte()? {
+                Some(c) if c.is_ascii_hexdigit() => {
+                    self.digit = (c as char).to_digit(16).unwrap_or_default() as u8;
+                    self.digit_bits = 4;
+                    self.digits += 1;
+                    return Ok(());
+                }
+                Some(c) if c.is_ascii_whitespace() => {
+                    self.ended = true;
+                    self.terminator = Some(c);
+                }
+                None => {
+                    self.ended = true;
+                    self.terminator = None;
+                }
+                Some(c) => {
+                    let c = self.read_char(c)?;
+                    let offset = 4 * self.digits;
+                    return Err(DecodeError::new(offset, DecodeErrorKind::UnexpectedChar(c)));
+                }
+            }
+        }
+
+        if self.digits % 2 == 1 {
+            let offset = 4 * (self.digits - 1);
+            return Err(DecodeError::new(offset, DecodeErrorKind::OddHexDigits));
+        }
+        Err(DecodeError::new(self.offset, DecodeErrorKind::Truncated))
+    }
+
+    /// Reads the rest of the UTF-8 character starting with `first`.
+    fn read_char(&mut self, first: u8) -> DecodeResult<char> {
+        let mut bytes = vec![first];
+        let len = first.leading_ones().clamp(1, 4) as usize;
+        while bytes.len() < len {
+            match self.read_byte()? {
+                Some(c) if c & 0xC0 == 0x80 => bytes.push(c),
+                other => {
+                    self.peeked = other;
+                    break;
+                }
+            }
+        }
+        Ok(String::from_utf8_lossy(&bytes)
+            .chars()
+            .next()
+            .unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Takes the bits following a packet up to the end of the transmission if they
+    /// are all zeros, otherwise leaves them to be read as the next packet.
+    fn skip_padding(&mut self) -> DecodeResult<bool> {
+        let start = self.offset;
+        loop {
+            match self.bit() {
+                Ok(false) => {}
+                Ok(true) => {
+                    self.replay_zeros = self.offset - 1 - start;
+                    self.replay_one = true;
+                    self.offset = start;
+                    return Ok(true);
+                }
+                Err(err) if err.kind == DecodeErrorKind::Truncated && self.ended => {
+                    return Ok(false)
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Skips the rest of the current transmission, returns false when there is no other.
+    fn next_transmission(&mut self) -> DecodeResult<bool> {
+        while !self.ended {
+            match self.read_byte()? {
+                Some(c) if c.is_ascii_whitespace() => self.ended = true,
+                Some(_) => {}
+                None => self.ended = true,
+            }
+        }
+        loop {
+            match self.read_byte()? {
+                Some(c) if c.is_ascii_whitespace() => {}
+                Some(c) => {
+                    self.peeked = Some(c);
+                    break;
+                }
+                None => return Ok(false),
+            }
+        }
+
+        self.line = self.newlines + 1;
+        self.offset = 0;
+        self.limit = usize::MAX;
+        self.digits = 0;
+        self.digit_bits = 0;
+        self.replay_zeros = 0;
+        self.replay_one = false;
+        self.ended = false;
+        self.terminator = None;
+        Ok(true)
+    }
+
+    fn read_byte(&mut self) -> DecodeResult<Option<u8>> {
+        if let Some(c) = self.peeked.take() {
+            return Ok(Some(c));
+        }
+        let byte = self
+            .bytes
+            .next()
+            .transpose()
+            .map_err(|e| DecodeError::new(self.offset, DecodeErrorKind::Read(e.to_string())))?;
+        if byte == Some(b'\n') {
+            self.newlines += 1;
+        }
+        Ok(byte)
+    }
+}
+
+/// Compiles an infix expression such as `min(3, 4*5) + (7 > 2)` to the packet
+/// evaluating it, all packets have version 0. Supported are `+` and `*`, comparisons
+/// `>`, `<` and `==` of two operands, `sum`, `product`, `min` and `max` functions
+/// and parentheses.
+pub fn compile(expression: &str) -> Result<Packet> {
+    let mut parser = ExpressionParser {
+        input: expression,
+        pos: 0,
+        depth: 0,
+    };
+
+    let packet = parser.comparison()?;
+    if parser.peek().is_some() {
+        return Err(parser.unexpected());
+    }
+    // Operators add packet levels of their own, so the packet may be nested deeper than
+    // the expression is.
+    if packet.depth() > MAX_DEPTH {
+        return Err(Error::parse("expression nested too deeply"));
+    }
+    Ok(packet)
+}
+
+/// Recursive descent parser of infix expressions, errors carry the column of the
+/// offending character.
+struct ExpressionParser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl ExpressionParser<'_> {
+    fn comparison(&mut self) -> Result<Packet> {
+        let lhs = self.sum()?;
+        let packet_type = match self.peek() {
+            Some(b'>') => PacketType::Greater,
+            Some(b'<') => PacketType::Less,
+            Some(b'=') => {
+                // The second `=` must follow right away.
+                self.pos += 1;
+                if self.input.as_bytes().get(self.pos) != Some(&b'=') {
+                    return Err(self.unexpected());
+                }
+                PacketType::Equal
+            }
+            _ => return Ok(lhs),
+        };
+        self.pos += 1;
+        let rhs = self.sum()?;
+        Ok(Packet::operator(0, packet_type, vec![lhs, rhs]))
+    }
+
+    fn sum(&mut self) -> Result<Packet> {
+        let mut terms = vec![self.product()?];
+        while self.peek() == Some(b'+') {
+            self.pos += 1;
+            terms.push(self.product()?);
+        }
+        Ok(Self::chain(PacketType::Sum, terms))
+    }
+
+    fn product(&mut self) -> Result<Packet> {
+        let mut factors = vec![self.atom()?];
+        while self.peek() == Some(b'*') {
+            self.pos += 1;
+            factors.push(self.atom()?);
+        }
+        Ok(Self::chain(PacketType::Product, factors))
+    }
+
+    /// Chained operations become a single packet, e.g. `1 + 2 + 3` is `(+ 1 2 3)`.
+    fn chain(packet_type: PacketType, mut operands: Vec<Packet>) -> Packet {
+        if operands.len() == 1 {
+            return operands.remove(0);
+        }
+        Packet::operator(0, packet_type, operands)
+    }
+
+    fn atom(&mut self) -> Result<Packet> {
+        match self.peek() {
+            Some(b'(') => {
+                self.enter()?;
+                self.pos += 1;
+                let packet = self.comparison()?;
+                self.expect(b')')?;
+                self.depth -= 1;
+                Ok(packet)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.pos;
+                let digits = self.take_while(|c| c.is_ascii_digit());
+                let value = digits
+                    .parse()
+                    .map_err(|e| Error::from(e).at_column(start + 1))?;
+                Ok(Packet::literal(0, value))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.pos;
+                let packet_type = match self.take_while(|c| c.is_ascii_alphabetic()) {
+                    "sum" => PacketType::Sum,
+                    "product" => PacketType::Product,
+                    "min" => PacketType::Min,
+                    "max" => PacketType::Max,
+                    name => {
+                        return Err(Error::parse(format!("unknown function: {}", name))
+                            .at_column(start + 1))
+                    }
+                };
+                self.enter()?;
+                self.expect(b'(')?;
+                let mut arguments = vec![self.comparison()?];
+                while self.peek() == Some(b',') {
+                    self.pos += 1;
+                    arguments.push(self.comparison()?);
+                }
+                self.expect(b')')?;
+                self.depth -= 1;
+                Ok(Packet::operator(0, packet_type, arguments))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn enter(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(Error::parse("expression nested too deeply").at_column(self.pos + 1));
+        }
+        Ok(())
+    }
+
+    fn take_while<F: Fn(u8) -> bool>(&mut self, f: F) -> &str {
+        let start = self.pos;
+        while self.input.as_bytes().get(self.pos).is_some_and(|&c| f(c)) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        if self.peek() != Some(expected) {
+            return Err(self.unexpected());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Next character which is not whitespace.
+    fn peek(&mut self) -> Option<u8> {
+        self.take_while(|c| c.is_ascii_whitespace());
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    // Parser only moves over ASCII characters, so `pos` is always a char boundary.
+    fn unexpected(&self) -> Error {
+        match self.input[self.pos..].chars().next() {
+            Some(c) => Error::unexpected_char(c).at_column(self.pos + 1),
+            None => Error::parse("unexpected end of expression").at_column(self.pos + 1),
+        }
+    }
+}
+
+pub fn day_16_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    Ok(Packet::read(File::open(input_file)?)?.sum_versions())
+}
+
+pub fn day_16_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    Packet::read(File::open(input_file)?)?.eval()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u16 {
+        16
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(parse_top_packet(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day16::{
+        compile, part_1, part_2, Day16, DecodeError, DecodeErrorKind, LengthType, Packet,
+        PacketContent, PacketReader, PacketType,
+    };
+    use proptest::prelude::*;
+
+    const EXAMPLES: &[Example] = &[
+        Example {
+            input: "8A004A801A8002F478",
+            part_1: Some("16"),
+            part_2: None,
+        },
+        Example {
+            input: "620080001611562C8802118E34",
+            part_1: Some("12"),
+            part_2: None,
+        },
+        Example {
+            input: "C0015000016115A2E0802F182340",
+            part_1: Some("23"),
+            part_2: None,
+        },
+        Example {
+            input: "A0016C880162017C3686B18A3D4780",
+            part_1: Some("31"),
+            part_2: None,
+        },
+        Example {
+            input: "C200B40A82",
+            part_1: None,
+            part_2: Some("3"),
+        },
+        Example {
+            input: "04005AC33890",
+            part_1: None,
+            part_2: Some("54"),
+        },
+        Example {
+            input: "880086C3E88112",
+            part_1: None,
+            part_2: Some("7"),
+        },
+        Example {
+            input: "CE00C43D881120",
+            part_1: None,
+            part_2: Some("9"),
+        },
+        Example {
+            input: "D8005AC2A8F0",
+            part_1: None,
+            part_2: Some("1"),
+        },
+        Example {
+            input: "F600BC2D8F",
+            part_1: None,
+            part_2: Some("0"),
+        },
+        Example {
+            input: "9C005AC2F8F0",
+            part_1: None,
+            part_2: Some("0"),
+        },
+        Example {
+            input: "9C0141080250320F1802104A08",
+            part_1: None,
+            part_2: Some("1"),
+        },
+    ];
+
+    #[test]
+    fn test() {
+        check_examples(&Day16, EXAMPLES);
+
+        let err = part_1("8A004G").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: unexpected character: 'G'"
+        );
+        let err = part_1("8A00").unwrap_err();
+        assert_eq!(err.to_string(), "bit 16: unexpected end of packet");
+        let err = part_2("C20").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: odd number of hex digits"
+        );
+
+        // Examples are encoded back the same way, whichever length type they use.
+        for example in EXAMPLES.iter().chain(&[Example {
+            input: "D2FE28",
+            part_1: None,
+            part_2: None,
+        }]) {
+            let packet: Packet = example.input.parse().unwrap();
+            assert_eq!(packet.to_hex().unwrap(), example.input);
+        }
+
+        let packet: Packet = "9C0141080250320F1802104A08".parse().unwrap();
+        assert_eq!(packet.to_string(), "(= (+ 1 3) (* 2 2))");
+        let packet = Packet::operator(
+            0,
+            PacketType::Sum,
+            vec![
+                Packet::operator(
+                    0,
+                    PacketType::Product,
+                    vec![Packet::literal(0, 2), Packet::literal(0, 3)],
+                ),
+                Packet::operator(
+                    0,
+                    PacketType::Min,
+                    vec![Packet::literal(0, 7), Packet::literal(0, 8)],
+                ),
+            ],
+        );
+        assert_eq!(packet.to_string(), "(+ (* 2 3) (min 7 8))");
+        assert_eq!(packet.to_hex().unwrap().parse(), Ok(packet));
+
+        let err = Packet::literal(8, 1).to_hex().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to encode: version 8 does not fit in 3 bits"
+        );
+        let err = Packet::operator(0, PacketType::Max, vec![])
+            .to_hex()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to encode: Max packet cannot have 0 sub-packets"
+        );
+
+        // Malformed transmissions are located by the offset of the offending bit.
+        let decode_err = |hex: &str| Packet::decode(hex).unwrap_err();
+        assert_eq!(
+            decode_err("D2FE29"),
+            DecodeError {
+                offset: 23,
+                kind: DecodeErrorKind::NonZeroPadding
+            }
+        );
+        assert_eq!(
+            decode_err("1600C408821060"),
+            DecodeError {
+                offset: 6,
+                kind: DecodeErrorKind::OperandCount {
+                    packet_type: PacketType::Greater,
+                    count: 3
+                }
+            }
+        );
+        assert_eq!(
+            decode_err("020000").kind,
+            DecodeErrorKind::OperandCount {
+                packet_type: PacketType::Sum,
+                count: 0
+            }
+        );
+        assert_eq!(
+            decode_err("13FFFFFFFFFFFFFFFFFFFDE0"),
+            DecodeError {
+                offset: 86,
+                kind: DecodeErrorKind::LiteralOverflow
+            }
+        );
+        assert_eq!(decode_err("C2").kind, DecodeErrorKind::Truncated);
+        assert_eq!(decode_err("C20").kind, DecodeErrorKind::OddHexDigits);
+        assert_eq!(
+            part_1("D2FE29").unwrap_err().to_string(),
+            "bit 23: padding after the packet is not zero"
+        );
+        assert_eq!(
+            decode_err("D2FE28 38006F45291200"),
+            DecodeError {
+                offset: 24,
+                kind: DecodeErrorKind::UnexpectedChar(' ')
+            }
+        );
+        assert_eq!(decode_err("").kind, DecodeErrorKind::Truncated);
+        let nested = (0..101).fold(Packet::literal(0, 1), |packet, _| {
+            Packet::operator(0, PacketType::Sum, vec![packet])
+        });
+        assert_eq!(
+            decode_err(&nested.to_hex().unwrap()),
+            DecodeError {
+                offset: 18 * 101,
+                kind: DecodeErrorKind::NestedTooDeeply
+            }
+        );
+
+        // Packets stream one after another, within a transmission and across lines.
+        let read_all = |input: &str| {
+            let mut reader = PacketReader::new(input.as_bytes());
+            let mut read = Vec::new();
+            while let Some(packet) = reader.next() {
+                read.push((reader.line(), packet.map(|packet| packet.to_string())));
+            }
+            read
+        };
+        assert_eq!(
+            read_all("D2FE28\nC200B40A82\n\n9C0141080250320F1802104A08\n"),
+            vec![
+                (1, Ok("2021".to_string())),
+                (2, Ok("(+ 1 2)".to_string())),
+                (4, Ok("(= (+ 1 3) (* 2 2))".to_string()))
+            ]
+        );
+        // D2FE28 followed by C200B40A82 without the padding of the first packet.
+        assert_eq!(
+            read_all("D2FE2E1005A05410"),
+            vec![(1, Ok("2021".to_string())), (1, Ok("(+ 1 2)".to_string()))]
+        );
+        let truncated = DecodeError {
+            offset: 8,
+            kind: DecodeErrorKind::Truncated,
+        };
+        assert_eq!(
+            read_all("D2FE28\nC2\nD2FE28 A0"),
+            vec![
+                (1, Ok("2021".to_string())),
+                (2, Err(truncated.clone())),
+                (3, Ok("2021".to_string())),
+                (3, Err(truncated)),
+            ]
+        );
+        assert_eq!(
+            read_all("D2FE28X0\nD2FE28"),
+            vec![
+                (1, Ok("2021".to_string())),
+                (
+                    1,
+                    Err(DecodeError {
+                        offset: 24,
+                        kind: DecodeErrorKind::UnexpectedChar('X')
+                    })
+                ),
+                (2, Ok("2021".to_string())),
+            ]
+        );
+
+        let packet = "9C0141080250320F1802104A08".parse::<Packet>().unwrap();
+        let transmissions = vec![packet.to_hex().unwrap(); 50_000].join("\n");
+        let mut reader = PacketReader::new(transmissions.as_bytes());
+        assert!(reader
+            .by_ref()
+            .take(50_000)
+            .all(|read| read == Ok(packet.clone())));
+        assert_eq!(reader.next(), None);
+
+        let err = part_2(
+            &compile("4294967296 * 4294967296")
+                .unwrap()
+                .to_hex()
+                .unwrap(),
+        );
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "no solution: value of Product packet overflows"
+        );
+
+        let packet = compile("min(3, 4*5) + (7 > 2)").unwrap();
+        assert_eq!(packet.to_string(), "(+ (min 3 (* 4 5)) (> 7 2))");
+        assert_eq!(part_2(&packet.to_hex().unwrap()), Ok(4));
+        let packet = compile(" 1+2 +3*4*5 == sum(max(1,2), product(3))").unwrap();
+        assert_eq!(
+            packet.to_string(),
+            "(= (+ 1 2 (* 3 4 5)) (+ (max 1 2) (* 3)))"
+        );
+        assert_eq!(packet.eval(), Ok(0));
+
+        let err = compile("2 + ").unwrap_err();
+        assert_eq!(err.to_string(), "column 5: unexpected end of expression");
+        let err = compile("avg(1, 2)").unwrap_err();
+        assert_eq!(err.to_string(), "column 1: unknown function: avg");
+        let err = compile("1 > 2 > 3").unwrap_err();
+        assert_eq!(err.to_string(), "column 7: unexpected character: '>'");
+        let err = compile("1 = 2").unwrap_err();
+        assert_eq!(err.to_string(), "column 4: unexpected character: ' '");
+        let err = compile(&format!("{}1{}", "(".repeat(200), ")".repeat(200))).unwrap_err();
+        assert_eq!(err.to_string(), "column 101: expression nested too deeply");
+
+        // Every `sum(1*` nests two packets, compiled packets must still decode.
+        let nested = |levels: usize| format!("{}1{}", "sum(1*".repeat(levels), ")".repeat(levels));
+        let packet = compile(&nested(50)).unwrap();
+        assert_eq!(Packet::decode(&packet.to_hex().unwrap()), Ok(packet));
+        let err = compile(&format!("1+{}", nested(50))).unwrap_err();
+        assert_eq!(err, Error::parse("expression nested too deeply"));
+    }
+
+    /// Infix expressions along with their values, `None` when they overflow.
+    fn expressions() -> impl Strategy<Value = (String, Option<usize>)> {
+        let number = (0..1000usize).prop_map(|n| (n.to_string(), Some(n)));
+        number.prop_recursive(4, 32, 4, |inner| {
+            let binary = (
+                inner.clone(),
+                prop::sample::select(vec!["+", "*", ">", "<", "=="]),
+                inner.clone(),
+            )
+                .prop_map(|((lhs, a), op, (rhs, b))| {
+                    let value = a.zip(b).and_then(|(a, b)| match op {
+                        "+" => a.checked_add(b),
+                        "*" => a.checked_mul(b),
+                        ">" => Some((a > b).into()),
+                        "<" => Some((a < b).into()),
+                        _ => Some((a == b).into()),
+                    });
+                    (format!("({} {} {})", lhs, op, rhs), value)
+                });
+            let function = (
+                prop::sample::select(vec!["sum", "product", "min", "max"]),
+                prop::collection::vec(inner, 1..4),
+            )
+                .prop_map(|(name, arguments)| {
+                    let values: Option<Vec<usize>> = arguments.iter().map(|(_, v)| *v).collect();
+                    let value = values.and_then(|values| match name {
+                        "sum" => values.iter().try_fold(0usize, |acc, &v| acc.checked_add(v)),
+                        "product" => values.iter().try_fold(1usize, |acc, &v| acc.checked_mul(v)),
+                        "min" => values.iter().copied().min(),
+                        _ => values.iter().copied().max(),
+                    });
+                    let arguments: Vec<String> =
+                        arguments.into_iter().map(|(text, _)| text).collect();
+                    (format!("{}({})", name, arguments.join(", ")), value)
+                });
+            prop_oneof![binary, function]
+        })
+    }
+
+    fn packets() -> impl Strategy<Value = Packet> {
+        let literal = (0..8usize, any::<usize>())
+            .prop_map(|(version, value)| Packet::literal(version, value));
+        literal.prop_recursive(4, 32, 4, |inner| {
+            let variadic = (
+                prop::sample::select(vec![
+                    PacketType::Sum,
+                    PacketType::Product,
+                    PacketType::Min,
+                    PacketType::Max,
+                ]),
+                prop::collection::vec(inner.clone(), 1..4),
+            );
+            let comparison = (
+                prop::sample::select(vec![
+                    PacketType::Greater,
+                    PacketType::Less,
+                    PacketType::Equal,
+                ]),
+                prop::collection::vec(inner, 2),
+            );
+            let length_types =
+                prop::sample::select(vec![LengthType::TotalBits, LengthType::PacketCount]);
+            (0..8usize, prop_oneof![variadic, comparison], length_types).prop_map(
+                |(version, (packet_type, packets), length_type)| Packet {
+                    version,
+                    content: PacketContent::SubPackets {
+                        length_type,
+                        packets,
+                    },
+                    packet_type,
+                },
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn decode_never_panics_on_text(s in "\\PC*") {
+            let _ = Packet::decode(&s);
+        }
+
+        #[test]
+        fn decode_never_panics(bytes in prop::collection::vec(any::<u8>(), 0..64)) {
+            let hex: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+            if let Err(err) = Packet::decode(&hex) {
+                prop_assert!(err.offset <= 8 * bytes.len());
+            }
+        }
+
+        #[test]
+        fn packet_reader_never_panics(s in "[0-9A-F\n ]{0,64}") {
+            prop_assert!(PacketReader::new(s.as_bytes()).count() <= s.len());
+        }
+
+        #[test]
+        fn packets_round_trip(packet in packets()) {
+            let hex = packet.to_hex().unwrap();
+            prop_assert_eq!(hex.parse::<Packet>(), Ok(packet));
+        }
+
+        #[test]
+        fn compiled_expressions_evaluate((expression, value) in expressions()) {
+            prop_assume!(value.is_some());
+            let hex = compile(&expression).unwrap().to_hex().unwrap();
+            prop_assert_eq!(part_2(&hex).ok(), value);
+        }
+
+        #[test]
+        fn compile_never_panics(s in "[0-9a-z+*<>=(), ]{0,64}") {
+            let _ = compile(&s);
+        }
+
+        #[test]
+        fn part_1_never_panics(s in "[0-9A-F]{0,64}") {
+            let _ = part_1(&s);
+        }
+    }
+}
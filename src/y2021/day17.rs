@@ -1,5 +1,8 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, split_pair, strip_prefix};
 use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::hint::black_box;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -8,34 +11,28 @@ struct Range(i32, i32);
 
 impl Range {
     fn new(a: i32, b: i32) -> Range {
-        Range {
-            0: a.min(b),
-            1: a.max(b),
-        }
+        Range(a.min(b), a.max(b))
     }
 }
 
 impl FromStr for Range {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let val = s
-            .split_once("..")
-            .map(|(min, max)| (min.parse().unwrap(), max.parse().unwrap()))
-            .unwrap();
-        Ok(Range::new(val.0, val.1))
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (min, max) = split_pair(s, "..")?;
+        Ok(Range::new(min.parse()?, max.parse()?))
     }
 }
 
-pub fn day_17_1<P: AsRef<Path>>(input_file: P) -> usize {
-    let (_, y) = read_ranges(input_file);
-    let max_y_vel = y.0.abs() as usize - 1;
+pub fn part_1(input: &str) -> Result<usize> {
+    let (_, y) = read_ranges(input)?;
+    let max_y_vel = y.0.unsigned_abs() as usize - 1;
 
-    (max_y_vel) * (max_y_vel + 1) / 2
+    Ok((max_y_vel) * (max_y_vel + 1) / 2)
 }
 
-pub fn day_17_2<P: AsRef<Path>>(input_file: P) -> usize {
-    let (x, y) = read_ranges(input_file);
+pub fn part_2(input: &str) -> Result<usize> {
+    let (x, y) = read_ranges(input)?;
 
     let max_y_vel = y.0.abs() - 1;
     let min_y_vel = y.0;
@@ -58,21 +55,23 @@ pub fn day_17_2<P: AsRef<Path>>(input_file: P) -> usize {
         calc_points(y_val, &steps_to_x_val, y, &mut hash_set);
     }
 
-    hash_set.len()
+    Ok(hash_set.len())
 }
 
-fn read_ranges<P: AsRef<Path>>(input_file: P) -> (Range, Range) {
-    let data = fs::read_to_string(input_file).expect("failed to read input");
-    let (x_range, y_range) = data
-        .strip_prefix("target area: ")
-        .unwrap()
-        .split_once(", ")
-        .unwrap();
+fn read_ranges(input: &str) -> Result<(Range, Range)> {
+    let (x_range, y_range) = split_pair(strip_prefix(input.trim_end(), "target area: ")?, ", ")?;
 
-    let x = Range::from_str(x_range.strip_prefix("x=").unwrap()).unwrap();
-    let y = Range::from_str(y_range.strip_prefix("y=").unwrap()).unwrap();
+    let x = Range::from_str(strip_prefix(x_range, "x=")?)?;
+    let y = Range::from_str(strip_prefix(y_range, "y=")?)?;
 
-    (x, y)
+    // Both parts rely on the target being ahead and below the probe.
+    if x.0 <= 0 || y.1 >= 0 {
+        return Err(Error::no_solution(
+            "target area must be ahead and below the start",
+        ));
+    }
+
+    Ok((x, y))
 }
 
 fn get_xes_at_steps(
@@ -138,16 +137,48 @@ fn calc_x_min(min_x: i32) -> i32 {
     val
 }
 
+pub fn day_17_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_17_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u16 {
+        17
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_ranges(input)?);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::day17::{day_17_1, day_17_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day17::Day17;
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: "target area: x=20..30, y=-10..-5",
+        part_1: Some("45"),
+        part_2: Some("112"),
+    }];
 
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_17", "target area: x=20..30, y=-10..-5");
-
-        assert_eq!(day_17_1(&file), 45);
-        assert_eq!(day_17_2(&file), 112);
+        check_examples(&Day17, EXAMPLES);
     }
 }
@@ -0,0 +1,611 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_lines, read_input};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::ops::Add;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Clone, Debug)]
+struct Explosion {
+    digit: Box<Digit>,
+    left_rem: u64,
+    right_rem: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Reminder {
+    Left(u64),
+    Right(u64),
+}
+
+/// Snailfish number kept as a binary tree of pairs.
+#[derive(Clone, Debug)]
+pub struct Number {
+    lhs: Box<Digit>,
+    rhs: Box<Digit>,
+}
+
+impl FromStr for Number {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parser = NumberParser { input: s, pos: 0 };
+
+        let number = parser.number(0)?;
+        if parser.peek().is_some() {
+            return Err(parser.unexpected());
+        }
+        Ok(number)
+    }
+}
+
+/// Recursive descent parser of snailfish numbers, errors carry the column
+/// of the offending character.
+struct NumberParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl NumberParser<'_> {
+    // Reduction relies on pairs never being nested deeper than this.
+    const MAX_DEPTH: u8 = 4;
+
+    fn number(&mut self, depth: u8) -> Result<Number> {
+        if depth == Self::MAX_DEPTH {
+            return Err(Error::parse("pair nested too deeply").at_column(self.pos + 1));
+        }
+        self.expect(b'[')?;
+        let lhs = self.digit(depth)?;
+        self.expect(b',')?;
+        let rhs = self.digit(depth)?;
+        self.expect(b']')?;
+
+        Ok(Number::new(lhs, rhs))
+    }
+
+    fn digit(&mut self, depth: u8) -> Result<Digit> {
+        match self.peek() {
+            Some(b'[') => Ok(self.number(depth + 1)?.into()),
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let value = self.input[start..self.pos]
+                    .parse()
+                    .map_err(|e| Error::from(e).at_column(start + 1))?;
+                Ok(Digit::Single(value))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        if self.peek() != Some(expected) {
+            return Err(self.unexpected());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    // Parser only moves over ASCII characters, so `pos` is always a char boundary.
+    fn unexpected(&self) -> Error {
+        match self.input[self.pos..].chars().next() {
+            Some(c) => Error::unexpected_char(c).at_column(self.pos + 1),
+            None => Error::parse("unexpected end of number").at_column(self.pos + 1),
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let fmt = format!("[{},{}]", self.lhs, self.rhs);
+        f.write_str(&fmt)
+    }
+}
+
+impl Number {
+    fn new(lhs: Digit, rhs: Digit) -> Number {
+        Number {
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        }
+    }
+
+    /// Explodes and splits pairs until neither applies.
+    pub fn reduce(&mut self) {
+        loop {
+            if self.reduce_explosions(0).is_some() {
+                continue;
+            }
+            if self.reduce_slices() {
+                continue;
+            }
+            break;
+        }
+    }
+
+    // TODO: probably could further simplify this...
+    fn reduce_explosions(&mut self, depth: u8) -> Option<Option<Reminder>> {
+        // Sums of unreduced numbers nest deeper, the leftmost pair of regular numbers
+        // explodes first then.
+        if depth >= 3 {
+            if let Some(expl) = self.lhs.explode() {
+                self.lhs = expl.digit;
+                self.rhs.add_left(expl.right_rem);
+                return Some(Some(Reminder::Left(expl.left_rem)));
+            }
+        }
+
+        if let Some(reduction) = self.lhs.reduce_explosions(depth + 1) {
+            return match reduction {
+                Some(Reminder::Right(num)) => {
+                    self.rhs.add_left(num);
+                    Some(None) // Indicates no reminder left, but the operation was performed
+                }
+                r => Some(r),
+            };
+        }
+
+        if depth >= 3 {
+            if let Some(expl) = self.rhs.explode() {
+                self.rhs = expl.digit;
+                self.lhs.add_right(expl.left_rem);
+                return Some(Some(Reminder::Right(expl.right_rem)));
+            }
+        }
+
+        if let Some(reduction) = self.rhs.reduce_explosions(depth + 1) {
+            return match reduction {
+                Some(Reminder::Left(num)) => {
+                    self.lhs.add_right(num);
+                    Some(None) // Indicates no reminder left, but the operation was performed
+                }
+                r => Some(r),
+            };
+        }
+        None
+    }
+
+    fn reduce_slices(&mut self) -> bool {
+        // Stop on first slice, so that we can check for explosions immediately after.
+        self.lhs.slice() || self.rhs.slice()
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        self.lhs.magnitude() * 3 + self.rhs.magnitude() * 2
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Number::new(self.into(), rhs.into())
+    }
+}
+
+impl From<Number> for Digit {
+    fn from(num: Number) -> Self {
+        Digit::Number(num)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Digit {
+    Single(u64),
+    Number(Number),
+}
+
+impl Display for Digit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Digit::Single(num) => f.write_str(&num.to_string()),
+            Digit::Number(num) => f.write_str(&format!("{}", num)),
+        }
+    }
+}
+
+impl Digit {
+    fn reduce_explosions(&mut self, depth: u8) -> Option<Option<Reminder>> {
+        match self {
+            Digit::Single(_) => None,
+            Digit::Number(num) => num.reduce_explosions(depth),
+        }
+    }
+
+    fn slice(&mut self) -> bool {
+        match self {
+            Digit::Single(x) if *x >= 10 => {
+                let split: f64 = (*x as f64) / 2.;
+                let l = split.floor() as u64;
+                let r = split.ceil() as u64;
+
+                *self = Digit::Number(Number::new(Digit::Single(l), Digit::Single(r)));
+                true
+            }
+            Digit::Single(_) => false,
+            Digit::Number(num) => num.reduce_slices(),
+        }
+    }
+
+    fn explode(&self) -> Option<Explosion> {
+        match self {
+            Digit::Single(_) => None,
+            Digit::Number(num) => match (num.lhs.as_ref(), num.rhs.as_ref()) {
+                (Digit::Single(lhs), Digit::Single(rhs)) => Some(Explosion {
+                    digit: Box::new(Digit::Single(0)),
+                    left_rem: *lhs,
+                    right_rem: *rhs,
+                }),
+                // Only pairs of regular numbers explode, deeper ones first.
+                _ => None,
+            },
+        }
+    }
+
+    fn add_right(&mut self, value: u64) {
+        match self {
+            Digit::Single(v) => *v += value,
+            Digit::Number(num) => {
+                num.rhs.add_right(value);
+            }
+        }
+    }
+
+    fn add_left(&mut self, value: u64) {
+        match self {
+            Digit::Single(v) => *v += value,
+            Digit::Number(num) => {
+                num.lhs.add_left(value);
+            }
+        }
+    }
+
+    fn magnitude(&self) -> u64 {
+        match self {
+            Digit::Single(num) => *num,
+            Digit::Number(num) => num.magnitude(),
+        }
+    }
+}
+
+/// Snailfish number kept as its regular numbers from left to right, each with the
+/// number of pairs enclosing it.
+///
+/// Behaves the same as [`Number`], without allocating a node per pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlatNumber {
+    regulars: Vec<Regular>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Regular {
+    value: u64,
+    depth: u8,
+}
+
+impl FlatNumber {
+    // Pairs of regular numbers nested this deep explode.
+    const EXPLODE_DEPTH: u8 = 5;
+
+    /// Explodes and splits pairs until neither applies.
+    pub fn reduce(&mut self) {
+        let mut start = 0;
+        loop {
+            if let Some(i) = self.exploding_pair(start) {
+                self.explode(i);
+                // The zero left behind may pair with its left neighbour.
+                start = i.saturating_sub(1);
+                continue;
+            }
+            match self.regulars.iter().position(|r| r.value >= 10) {
+                Some(i) => {
+                    self.split(i);
+                    start = i;
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Index of the left side of the leftmost pair of regular numbers to explode, none
+    /// of them is before `start`.
+    fn exploding_pair(&self, start: usize) -> Option<usize> {
+        // The first neighbours of the same depth deep enough are a pair. Otherwise one of
+        // them is a right side whose left sibling is a pair as deep, which contains an
+        // earlier pair of regular numbers.
+        self.regulars[start..]
+            .windows(2)
+            .position(|w| w[0].depth >= Self::EXPLODE_DEPTH && w[0].depth == w[1].depth)
+            .map(|i| start + i)
+    }
+
+    fn explode(&mut self, i: usize) {
+        let (lhs, rhs) = (self.regulars[i], self.regulars[i + 1]);
+        if i > 0 {
+            self.regulars[i - 1].value += lhs.value;
+        }
+        if let Some(next) = self.regulars.get_mut(i + 2) {
+            next.value += rhs.value;
+        }
+        self.regulars[i] = Regular {
+            value: 0,
+            depth: lhs.depth - 1,
+        };
+        self.regulars.remove(i + 1);
+    }
+
+    fn split(&mut self, i: usize) {
+        let Regular { value, depth } = self.regulars[i];
+        let lhs = Regular {
+            value: value / 2,
+            depth: depth + 1,
+        };
+        let rhs = Regular {
+            value: value - lhs.value,
+            depth: depth + 1,
+        };
+        self.regulars[i] = lhs;
+        self.regulars.insert(i + 1, rhs);
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        // Neighbours of the same depth on the stack are always the two sides of a pair,
+        // which are folded into its magnitude.
+        let mut stack: Vec<Regular> = Vec::with_capacity(Self::EXPLODE_DEPTH as usize + 1);
+        for &regular in &self.regulars {
+            let mut top = regular;
+            while let Some(lhs) = stack.last().filter(|lhs| lhs.depth == top.depth) {
+                top = Regular {
+                    value: lhs.value * 3 + top.value * 2,
+                    depth: top.depth - 1,
+                };
+                stack.pop();
+            }
+            stack.push(top);
+        }
+        stack.first().map_or(0, |regular| regular.value)
+    }
+
+    fn fmt_digit(&self, f: &mut Formatter<'_>, i: &mut usize, depth: u8) -> std::fmt::Result {
+        let regular = self.regulars[*i];
+        if regular.depth == depth {
+            *i += 1;
+            return write!(f, "{}", regular.value);
+        }
+        f.write_str("[")?;
+        self.fmt_digit(f, i, depth + 1)?;
+        f.write_str(",")?;
+        self.fmt_digit(f, i, depth + 1)?;
+        f.write_str("]")
+    }
+}
+
+impl From<&Number> for FlatNumber {
+    fn from(number: &Number) -> Self {
+        fn flatten(digit: &Digit, depth: u8, regulars: &mut Vec<Regular>) {
+            match digit {
+                Digit::Single(value) => regulars.push(Regular {
+                    value: *value,
+                    depth,
+                }),
+                Digit::Number(num) => {
+                    flatten(&num.lhs, depth + 1, regulars);
+                    flatten(&num.rhs, depth + 1, regulars);
+                }
+            }
+        }
+
+        let mut regulars = vec![];
+        flatten(&number.lhs, 1, &mut regulars);
+        flatten(&number.rhs, 1, &mut regulars);
+        FlatNumber { regulars }
+    }
+}
+
+impl FromStr for FlatNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(FlatNumber::from(&s.parse::<Number>()?))
+    }
+}
+
+impl Display for FlatNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_digit(f, &mut 0, 0)
+    }
+}
+
+impl Add for FlatNumber {
+    type Output = FlatNumber;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.regulars.extend(rhs.regulars);
+        for regular in &mut self.regulars {
+            regular.depth += 1;
+        }
+        self
+    }
+}
+
+pub fn part_1(input: &str) -> Result<u64> {
+    let number = parse_numbers(input)?
+        .into_iter()
+        .reduce(|sum, num| {
+            let mut number = sum.add(num);
+            number.reduce();
+            number
+        })
+        .ok_or_else(|| Error::parse("no snailfish numbers"))?;
+
+    Ok(number.magnitude())
+}
+
+pub fn part_2(input: &str) -> Result<u64> {
+    let numbers = parse_numbers(input)?;
+
+    let mut max_magnitude = 0;
+
+    for i in 0..numbers.len() {
+        for k in 0..numbers.len() {
+            if i == k {
+                continue;
+            }
+            let mut number = numbers[i].clone().add(numbers[k].clone());
+            number.reduce();
+            let mag = number.magnitude();
+            max_magnitude = max_magnitude.max(mag);
+        }
+    }
+
+    Ok(max_magnitude)
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<FlatNumber>> {
+    parse_lines(input)
+}
+
+pub fn day_18_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_18_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> u16 {
+        18
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(parse_numbers(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day18::{part_1, Day18, FlatNumber, Number};
+    use proptest::prelude::*;
+
+    const TEST_DATA: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("4140"),
+        part_2: Some("3993"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day18, EXAMPLES);
+
+        let err = part_1("[1,2]\n[[1,2],3}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 9: unexpected character: '}'"
+        );
+        let err = part_1("[[[[[1,2],3],4],5],6]").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 5: pair nested too deeply");
+        assert_eq!(part_1("").unwrap_err().to_string(), "no snailfish numbers");
+
+        let lhs: FlatNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        let mut number = lhs + "[1,1]".parse().unwrap();
+        assert_eq!(number.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        number.reduce();
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        assert_eq!(number.magnitude(), 1384);
+
+        // Sums nested deeper than a single addition reduces still explode innermost first.
+        let deep = "[[[[1,1],1],1],1]";
+        let mut tree = deep.parse::<Number>().unwrap() + deep.parse().unwrap();
+        tree = tree + deep.parse().unwrap();
+        tree.reduce();
+        let mut flat = deep.parse::<FlatNumber>().unwrap() + deep.parse().unwrap();
+        flat = flat + deep.parse().unwrap();
+        flat.reduce();
+        assert_eq!(tree.to_string(), "[[[[0,3],2],[[0,3],2]],[[[0,2],1],1]]");
+        assert_eq!(flat.to_string(), tree.to_string());
+    }
+
+    /// Snailfish numbers nested no deeper than the parser accepts.
+    fn number() -> impl Strategy<Value = String> {
+        let digit = (0..100u64)
+            .prop_map(|value| value.to_string())
+            .prop_recursive(3, 16, 2, |inner| {
+                (inner.clone(), inner).prop_map(|(lhs, rhs)| format!("[{},{}]", lhs, rhs))
+            });
+        (digit.clone(), digit).prop_map(|(lhs, rhs)| format!("[{},{}]", lhs, rhs))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(s in "\\PC*") {
+            let _ = s.parse::<Number>();
+        }
+
+        #[test]
+        fn parse_never_panics_on_brackets(s in "[\\[\\],0-9]{0,40}") {
+            let _ = s.parse::<Number>();
+        }
+
+        #[test]
+        fn display_round_trips(s in number()) {
+            let number: Number = s.parse().unwrap();
+            prop_assert_eq!(&number.to_string(), &s);
+            let number: FlatNumber = s.parse().unwrap();
+            prop_assert_eq!(number.to_string(), s);
+        }
+
+        #[test]
+        fn flat_numbers_add_like_trees(first in number(), second in number(), third in number()) {
+            let mut tree = first.parse::<Number>().unwrap() + second.parse().unwrap();
+            let mut flat = first.parse::<FlatNumber>().unwrap() + second.parse().unwrap();
+            prop_assert_eq!(flat.to_string(), tree.to_string());
+            prop_assert_eq!(flat.magnitude(), tree.magnitude());
+
+            // Adding again before reducing nests pairs deeper than reduction assumes.
+            let (mut deep_tree, mut deep_flat) = (tree.clone(), flat.clone());
+            deep_tree = deep_tree + third.parse().unwrap();
+            deep_flat = deep_flat + third.parse().unwrap();
+            deep_tree.reduce();
+            deep_flat.reduce();
+            prop_assert_eq!(deep_flat.to_string(), deep_tree.to_string());
+            prop_assert_eq!(deep_flat.magnitude(), deep_tree.magnitude());
+
+            tree.reduce();
+            flat.reduce();
+            prop_assert_eq!(flat.to_string(), tree.to_string());
+            prop_assert_eq!(flat.magnitude(), tree.magnitude());
+        }
+    }
+}
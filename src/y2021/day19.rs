@@ -1,35 +1,38 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::trace;
+use crate::util::{blocks, parse_separated, read_input, Block};
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::fs;
+use std::hint::black_box;
 use std::iter::FromIterator;
 use std::path::Path;
-use std::str::FromStr;
 
 static ROTATIONS: [fn([i32; 3]) -> [i32; 3]; 24] = [
-    |[x, y, z]: [i32; 3]| ([x, y, z]),
-    |[x, y, z]: [i32; 3]| ([x, z, -y]),
-    |[x, y, z]: [i32; 3]| ([x, -y, -z]),
-    |[x, y, z]: [i32; 3]| ([x, -z, y]),
-    |[x, y, z]: [i32; 3]| ([y, x, -z]),
-    |[x, y, z]: [i32; 3]| ([y, z, x]),
-    |[x, y, z]: [i32; 3]| ([y, -x, z]),
-    |[x, y, z]: [i32; 3]| ([y, -z, -x]),
-    |[x, y, z]: [i32; 3]| ([z, x, y]),
-    |[x, y, z]: [i32; 3]| ([z, y, -x]),
-    |[x, y, z]: [i32; 3]| ([z, -x, -y]),
-    |[x, y, z]: [i32; 3]| ([z, -y, x]),
-    |[x, y, z]: [i32; 3]| ([-x, y, -z]),
-    |[x, y, z]: [i32; 3]| ([-x, z, y]),
-    |[x, y, z]: [i32; 3]| ([-x, -y, z]),
-    |[x, y, z]: [i32; 3]| ([-x, -z, -y]),
-    |[x, y, z]: [i32; 3]| ([-y, x, z]),
-    |[x, y, z]: [i32; 3]| ([-y, z, -x]),
-    |[x, y, z]: [i32; 3]| ([-y, -x, -z]),
-    |[x, y, z]: [i32; 3]| ([-y, -z, x]),
-    |[x, y, z]: [i32; 3]| ([-z, x, -y]),
-    |[x, y, z]: [i32; 3]| ([-z, -x, y]),
-    |[x, y, z]: [i32; 3]| ([-z, y, x]),
-    |[x, y, z]: [i32; 3]| ([-z, -y, -x]),
+    |[x, y, z]: [i32; 3]| [x, y, z],
+    |[x, y, z]: [i32; 3]| [x, z, -y],
+    |[x, y, z]: [i32; 3]| [x, -y, -z],
+    |[x, y, z]: [i32; 3]| [x, -z, y],
+    |[x, y, z]: [i32; 3]| [y, x, -z],
+    |[x, y, z]: [i32; 3]| [y, z, x],
+    |[x, y, z]: [i32; 3]| [y, -x, z],
+    |[x, y, z]: [i32; 3]| [y, -z, -x],
+    |[x, y, z]: [i32; 3]| [z, x, y],
+    |[x, y, z]: [i32; 3]| [z, y, -x],
+    |[x, y, z]: [i32; 3]| [z, -x, -y],
+    |[x, y, z]: [i32; 3]| [z, -y, x],
+    |[x, y, z]: [i32; 3]| [-x, y, -z],
+    |[x, y, z]: [i32; 3]| [-x, z, y],
+    |[x, y, z]: [i32; 3]| [-x, -y, z],
+    |[x, y, z]: [i32; 3]| [-x, -z, -y],
+    |[x, y, z]: [i32; 3]| [-y, x, z],
+    |[x, y, z]: [i32; 3]| [-y, z, -x],
+    |[x, y, z]: [i32; 3]| [-y, -x, -z],
+    |[x, y, z]: [i32; 3]| [-y, -z, x],
+    |[x, y, z]: [i32; 3]| [-z, x, -y],
+    |[x, y, z]: [i32; 3]| [-z, -x, y],
+    |[x, y, z]: [i32; 3]| [-z, y, x],
+    |[x, y, z]: [i32; 3]| [-z, -y, -x],
 ];
 
 struct ScannerData {
@@ -37,22 +40,23 @@ struct ScannerData {
     beacons_distances: HashSet<usize>,
 }
 
-impl FromStr for ScannerData {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let beacons: Vec<[i32; 3]> = s
-            .lines()
-            .skip(1)
-            .map(|line| {
-                let mut values = line.split(',').map(|v| v.parse().unwrap());
-                [
-                    values.next().unwrap(),
-                    values.next().unwrap(),
-                    values.next().unwrap(),
-                ]
-            })
-            .collect();
+impl ScannerData {
+    fn new_from_block(block: &Block) -> Result<ScannerData> {
+        let (header, lines) = block
+            .lines
+            .split_first()
+            .ok_or_else(|| Error::parse("missing scanner header"))?;
+        if !header.text.starts_with("--- scanner ") {
+            return Err(
+                Error::parse(format!("expected scanner header, got {:?}", header.text))
+                    .at_line(header.number),
+            );
+        }
+
+        let beacons: Vec<[i32; 3]> = lines
+            .iter()
+            .map(|line| parse_beacon(line.text).map_err(|e| e.at_line(line.number)))
+            .collect::<Result<_>>()?;
 
         let distance_set: HashSet<usize> = beacons
             .iter()
@@ -67,12 +71,23 @@ impl FromStr for ScannerData {
     }
 }
 
+fn parse_beacon(line: &str) -> Result<[i32; 3]> {
+    match parse_separated::<i32>(line, ',')?.as_slice() {
+        &[x, y, z] => Ok([x, y, z]),
+        values => Err(Error::parse(format!(
+            "expected 3 coordinates, got {}",
+            values.len()
+        ))),
+    }
+}
+
 fn merge_scanner_positions(
     absolute_locs: &mut HashSet<[i32; 3]>,
     distance_sets: &mut Vec<HashSet<usize>>,
     scanner_data: &ScannerData,
 ) -> Option<[i32; 3]> {
     for apply_rotation in ROTATIONS {
+        trace::count("rotations_tried", 1);
         let rotated: Vec<[i32; 3]> = scanner_data
             .beacons
             .iter()
@@ -104,16 +119,14 @@ fn merge_scanner_positions(
     None
 }
 
-pub fn day_19_1<P: AsRef<Path>>(input_file: P) -> usize {
-    let data = fs::read_to_string(input_file).expect("failed to read input file");
-    let (beacon_abs_pos, _) = determine_positions(&data);
+pub fn part_1(input: &str) -> Result<usize> {
+    let (beacon_abs_pos, _) = determine_positions(input)?;
 
-    beacon_abs_pos.len()
+    Ok(beacon_abs_pos.len())
 }
 
-pub fn day_19_2<P: AsRef<Path>>(input_file: P) -> usize {
-    let data = fs::read_to_string(input_file).expect("failed to read input file");
-    let (_, scanner_abs_pos) = determine_positions(&data);
+pub fn part_2(input: &str) -> Result<usize> {
+    let (_, scanner_abs_pos) = determine_positions(input)?;
 
     let mut max_dist = 0;
     for (i, pos) in scanner_abs_pos.iter().enumerate() {
@@ -122,14 +135,25 @@ pub fn day_19_2<P: AsRef<Path>>(input_file: P) -> usize {
         }
     }
 
-    max_dist
+    Ok(max_dist)
 }
 
-fn determine_positions(data: &str) -> (HashSet<[i32; 3]>, Vec<[i32; 3]>) {
-    let mut scanners_data: Vec<ScannerData> = data
-        .split("\n\n")
-        .map(|scanner| ScannerData::from_str(scanner).unwrap())
-        .collect();
+fn read_scanners_data(data: &str) -> Result<Vec<ScannerData>> {
+    let scanners: Vec<ScannerData> = blocks(data)
+        .map(|block| ScannerData::new_from_block(&block))
+        .collect::<Result<_>>()?;
+    if scanners.is_empty() {
+        return Err(Error::parse("no scanner reports"));
+    }
+    Ok(scanners)
+}
+
+// Absolute positions of beacons and scanners.
+type Positions = (HashSet<[i32; 3]>, Vec<[i32; 3]>);
+
+fn determine_positions(data: &str) -> Result<Positions> {
+    let _span = trace::span("day19::determine_positions");
+    let mut scanners_data = read_scanners_data(data)?;
 
     let first_scanner = scanners_data.remove(0);
 
@@ -144,6 +168,7 @@ fn determine_positions(data: &str) -> (HashSet<[i32; 3]>, Vec<[i32; 3]>) {
     scanner_absolute_pos.push([0, 0, 0]); // Scanner 0
 
     while !scanners_data.is_empty() {
+        let remaining = scanners_data.len();
         for i in (0..scanners_data.len()).rev() {
             if let Some(pos) = merge_scanner_positions(
                 &mut beacon_absolute_pos,
@@ -152,21 +177,61 @@ fn determine_positions(data: &str) -> (HashSet<[i32; 3]>, Vec<[i32; 3]>) {
             ) {
                 scanner_absolute_pos.push(pos);
                 scanners_data.swap_remove(i);
+                trace::count("scanners_merged", 1);
             }
         }
+        if scanners_data.len() == remaining {
+            return Err(Error::no_solution(format!(
+                "{} scanners do not overlap with the others",
+                remaining
+            )));
+        }
     }
 
-    (beacon_absolute_pos, scanner_absolute_pos)
+    Ok((beacon_absolute_pos, scanner_absolute_pos))
 }
 
 fn manhattan_distance([x1, y1, z1]: &[i32; 3], [x2, y2, z2]: &[i32; 3]) -> usize {
     ((x1 - x2).abs() + (y1 - y2).abs() + (z1 - z2).abs()) as usize
 }
 
+pub fn day_19_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_19_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u16 {
+        19
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_scanners_data(input)?);
+        Ok(())
+    }
+
+    fn is_slow(&self) -> bool {
+        true
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::day19::{day_19_1, day_19_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day19::Day19;
 
     const TEST_DATA: &str = "--- scanner 0 ---
 404,-588,-901
@@ -305,11 +370,14 @@ mod test {
 -652,-548,-490
 30,-46,-14";
 
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("79"),
+        part_2: Some("3621"),
+    }];
+
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_19", TEST_DATA);
-
-        assert_eq!(day_19_1(&file), 79);
-        assert_eq!(day_19_2(&file), 3621);
+        check_examples(&Day19, EXAMPLES);
     }
 }
@@ -0,0 +1,220 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, split_block_pair, Grid};
+use std::hint::black_box;
+use std::path::Path;
+
+struct Image {
+    pixels: Grid<usize>,
+    enhancement: Vec<usize>,
+    padding: usize,
+    runs: usize,
+    switch_default: bool,
+}
+
+impl Image {
+    fn new(s: &str, padding: usize) -> Result<Image> {
+        let (enhancement, pixels) = split_block_pair(s)?;
+
+        let enhancement: Vec<usize> = enhancement
+            .lines // For the purpose of test
+            .iter()
+            .flat_map(|line| {
+                line.text
+                    .chars()
+                    .enumerate()
+                    .map(move |(c, ch)| (line, c, ch))
+            })
+            .map(|(line, c, ch)| pixel_to_val(ch).map_err(|e| e.at(line.number, c + 1)))
+            .collect::<Result<_>>()?;
+        if enhancement.len() != 512 {
+            return Err(Error::parse(format!(
+                "expected 512 enhancement values, got {}",
+                enhancement.len()
+            )));
+        }
+
+        let image = Grid::parse_lines(pixels.lines, pixel_to_val)?;
+
+        let rows = image.rows() + 2 * padding;
+        let cols = image.cols() + 2 * padding;
+        let pixels = Grid::from_fn(rows, cols, |(r, c)| {
+            match (r.checked_sub(padding), c.checked_sub(padding)) {
+                (Some(r), Some(c)) => image.get((r, c)).copied().unwrap_or(0),
+                _ => 0,
+            }
+        });
+
+        // If that is not the case we assume default is 0 otherwise we could end up with infinite ones.
+        let switch_default = enhancement[0] == 1 && enhancement[511] == 0;
+
+        Ok(Image {
+            pixels,
+            enhancement,
+            padding,
+            runs: 0,
+            switch_default,
+        })
+    }
+
+    fn enhance(&mut self) {
+        let (rows, cols) = (self.pixels.rows(), self.pixels.cols());
+        let mut new_image = Grid::new(rows, cols, 0);
+
+        let start = self.padding - 1;
+
+        if self.switch_default {
+            self.switch_padding(&mut new_image, self.padding);
+        }
+
+        for (r, c) in self.pixels.positions() {
+            if (start..rows - start).contains(&r) && (start..cols - start).contains(&c) {
+                new_image[(r, c)] = self.enhancement[self.value_for_pixel(r, c)];
+            }
+        }
+
+        self.padding -= 1;
+        self.pixels = new_image;
+        self.runs += 1;
+    }
+
+    fn switch_padding(&self, new_image: &mut Grid<usize>, padding: usize) {
+        let default_val = if self.runs.is_multiple_of(2) {
+            self.enhancement[0]
+        } else {
+            self.enhancement[511]
+        };
+        let rows = new_image.rows();
+        let cols = new_image.cols();
+
+        for (r, c) in new_image.positions() {
+            if r < padding || r >= rows - padding || c < padding || c >= cols - padding {
+                new_image[(r, c)] = default_val;
+            }
+        }
+    }
+
+    fn value_for_pixel(&self, r: usize, c: usize) -> usize {
+        let top = value_for_row(&self.pixels.row(r - 1)[c - 1..c + 2], RowPosition::Top);
+        let mid = value_for_row(&self.pixels.row(r)[c - 1..c + 2], RowPosition::Middle);
+        let down = value_for_row(&self.pixels.row(r + 1)[c - 1..c + 2], RowPosition::Down);
+        top + mid + down
+    }
+}
+
+enum RowPosition {
+    Top,
+    Middle,
+    Down,
+}
+
+impl RowPosition {
+    fn to_base_power(&self) -> usize {
+        match self {
+            RowPosition::Top => 6,
+            RowPosition::Middle => 3,
+            RowPosition::Down => 0,
+        }
+    }
+}
+
+fn value_for_row(row: &[usize], pos: RowPosition) -> usize {
+    let mut pow = 1;
+    for _i in 0..pos.to_base_power() + 2 {
+        pow *= 2;
+    }
+
+    let mut value = 0;
+    for bin_val in row.iter().take(3) {
+        value += bin_val * pow;
+        pow /= 2;
+    }
+    value
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    count_pixels(input, 2)
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    count_pixels(input, 50)
+}
+
+fn count_pixels(data: &str, enhance_rounds: usize) -> Result<usize> {
+    let mut image = Image::new(data, enhance_rounds + 1)?;
+
+    for _i in 0..enhance_rounds {
+        image.enhance();
+    }
+
+    Ok(image.pixels.iter().sum())
+}
+
+fn pixel_to_val(c: char) -> Result<usize> {
+    match c {
+        '.' => Ok(0),
+        '#' => Ok(1),
+        _ => Err(Error::unexpected_char(c)),
+    }
+}
+
+pub fn day_20_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_20_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn day(&self) -> u16 {
+        20
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(Image::new(input, 1)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day20::Day20;
+
+    const TEST_DATA: &str =
+        "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##
+#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###
+.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.
+.#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....
+.#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..
+...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....
+..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("35"),
+        part_2: Some("3351"),
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day20, EXAMPLES);
+    }
+}
@@ -1,5 +1,8 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, split_pair};
 use std::collections::HashMap;
-use std::fs;
+use std::hint::black_box;
 use std::path::Path;
 
 /// MOVES_WEIGHT represents how many times the outcome of 3 throws can occur.
@@ -31,9 +34,8 @@ impl Player {
     }
 }
 
-pub fn day_21_1<P: AsRef<Path>>(input_file: P) -> usize {
-    let data = fs::read_to_string(input_file).expect("failed to read input file");
-    let mut players = read_players(&data);
+pub fn part_1(input: &str) -> Result<usize> {
+    let mut players = read_players(input)?;
 
     let mut player_turn = 0;
     let mut dice_rolls = 0;
@@ -51,18 +53,17 @@ pub fn day_21_1<P: AsRef<Path>>(input_file: P) -> usize {
         player_turn = (player_turn + 1) % 2;
     }
 
-    players[0].score.min(players[1].score) * dice_rolls
+    Ok(players[0].score.min(players[1].score) * dice_rolls)
 }
 
-pub fn day_21_2<P: AsRef<Path>>(input_file: P) -> usize {
-    let data = fs::read_to_string(input_file).expect("failed to read input file");
-    let players = read_players(&data);
+pub fn part_2(input: &str) -> Result<usize> {
+    let players = read_players(input)?;
 
     let mut mem = HashMap::new();
 
     let (score_1, score_2) = play_dirac_dice_game(players, 0, &mut mem);
 
-    score_1.max(score_2)
+    Ok(score_1.max(score_2))
 }
 
 fn play_dirac_dice_game(
@@ -99,32 +100,67 @@ fn play_dirac_dice_game(
     scores
 }
 
-fn read_players(data: &str) -> [Player; 2] {
-    let (p1, p2) = data
-        .split_once('\n')
-        .map(|(l1, l2)| (player_position(l1), player_position(l2)))
-        .unwrap();
+fn read_players(data: &str) -> Result<[Player; 2]> {
+    let (l1, l2) = split_pair(data.trim_end(), "\n")?;
+    let p1 = player_position(l1).map_err(|e| e.at_line(1))?;
+    let p2 = player_position(l2).map_err(|e| e.at_line(2))?;
 
-    [Player::new(p1), Player::new(p2)]
+    Ok([Player::new(p1), Player::new(p2)])
 }
 
-fn player_position(data: &str) -> usize {
-    data.chars().last().unwrap().to_digit(10).unwrap() as usize
+fn player_position(data: &str) -> Result<usize> {
+    let (_, position) = split_pair(data, "starting position: ")?;
+    match position.parse()? {
+        pos @ 1..=10 => Ok(pos),
+        pos => Err(Error::parse(format!("position out of the board: {}", pos))),
+    }
+}
+
+pub fn day_21_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_21_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> u16 {
+        21
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_players(input)?);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::day21::{day_21_1, day_21_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day21::Day21;
 
     const TEST_DATA: &str = "Player 1 starting position: 4
 Player 2 starting position: 8";
 
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("739785"),
+        part_2: Some("444356092776315"),
+    }];
+
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_21", TEST_DATA);
-
-        assert_eq!(day_21_1(&file), 739785);
-        assert_eq!(day_21_2(&file), 444356092776315);
+        check_examples(&Day21, EXAMPLES);
     }
 }
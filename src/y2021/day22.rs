@@ -1,11 +1,13 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{parse_lines, read_input, split_pair, strip_prefix};
 use std::fmt::Formatter;
-use std::fs;
+use std::hint::black_box;
 use std::path::Path;
 use std::str::FromStr;
 
-pub fn day_22_1<P: AsRef<Path>>(input_file: P) -> usize {
-    let data = fs::read_to_string(input_file).expect("failed to read input file");
-    let cuboids = read_cuboids(&data);
+pub fn part_1(input: &str) -> Result<usize> {
+    let cuboids = read_cuboids(input)?;
 
     let initialization_area = Cuboid {
         is_on: false,
@@ -13,13 +15,12 @@ pub fn day_22_1<P: AsRef<Path>>(input_file: P) -> usize {
         y: Range::new(-50, 50),
         z: Range::new(-50, 50),
     };
-    find_volume(cuboids, Some(initialization_area))
+    Ok(find_volume(cuboids, Some(initialization_area)))
 }
 
-pub fn day_22_2<P: AsRef<Path>>(input_file: P) -> usize {
-    let data = fs::read_to_string(input_file).expect("failed to read input file");
-    let cuboids = read_cuboids(&data);
-    find_volume(cuboids, None)
+pub fn part_2(input: &str) -> Result<usize> {
+    let cuboids = read_cuboids(input)?;
+    Ok(find_volume(cuboids, None))
 }
 
 fn find_volume(cuboids: Vec<Cuboid>, area: Option<Cuboid>) -> usize {
@@ -66,15 +67,12 @@ struct Range {
 }
 
 impl FromStr for Range {
-    type Err = ();
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right): (i32, i32) = s[2..]
-            .split_once("..")
-            .map(|r| (r.0.parse().unwrap(), r.1.parse().unwrap()))
-            .unwrap();
+    fn from_str(s: &str) -> Result<Self> {
+        let (left, right) = split_pair(s, "..")?;
 
-        Ok(Range::new(left, right))
+        Ok(Range::new(left.parse()?, right.parse()?))
     }
 }
 
@@ -97,7 +95,7 @@ impl Range {
     }
 
     fn length(&self) -> usize {
-        (self.max - self.min).abs() as usize + 1
+        (self.max - self.min).unsigned_abs() as usize + 1
     }
 }
 
@@ -133,46 +131,92 @@ impl Cuboid {
 }
 
 impl FromStr for Cuboid {
-    type Err = ();
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (on_off, ranges) = s.split_once(' ').unwrap();
+    fn from_str(s: &str) -> Result<Self> {
+        let (on_off, ranges) = split_pair(s, " ")?;
 
         let is_on = match on_off {
             "on" => true,
-            _ => false,
+            "off" => false,
+            other => return Err(Error::parse(format!("expected on or off, got {:?}", other))),
         };
 
-        let mut ranges = ranges
-            .split(',')
-            .map(|range| Range::from_str(range).unwrap());
+        // Column of the current range, for the sake of error messages.
+        let mut column = on_off.len() + 2;
+        let mut ranges = ranges.split(',');
+        let mut next_range = |axis: &str| {
+            let range = ranges
+                .next()
+                .ok_or_else(|| Error::parse(format!("missing {} range", axis)))?;
+            let parsed = strip_prefix(range, &format!("{}=", axis))
+                .and_then(Range::from_str)
+                .map_err(|e| e.at_column(column));
+            column += range.len() + 1;
+            parsed
+        };
 
-        Ok(Cuboid {
+        let cuboid = Cuboid {
             is_on,
-            x: ranges.next().unwrap(),
-            y: ranges.next().unwrap(),
-            z: ranges.next().unwrap(),
-        })
+            x: next_range("x")?,
+            y: next_range("y")?,
+            z: next_range("z")?,
+        };
+        if ranges.next().is_some() {
+            return Err(Error::parse("unexpected range after z").at_column(column));
+        }
+        Ok(cuboid)
     }
 }
 
 impl std::fmt::Display for Cuboid {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let on_off = if self.is_on { "on" } else { "off" };
         f.write_str(&format!(
-            "on: {},  x: {},  y: {},  x: {}",
-            self.is_on, self.x, self.y, self.z
+            "{} x={},y={},z={}",
+            on_off, self.x, self.y, self.z
         ))
     }
 }
 
-fn read_cuboids(data: &str) -> Vec<Cuboid> {
-    data.lines().map(|l| Cuboid::from_str(l).unwrap()).collect()
+fn read_cuboids(data: &str) -> Result<Vec<Cuboid>> {
+    parse_lines(data.trim_end())
+}
+
+pub fn day_22_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_22_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> u16 {
+        22
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(read_cuboids(input)?);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::day22::{day_22_1, day_22_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day22::{part_2, Cuboid, Day22};
+    use proptest::prelude::*;
 
     const TEST_DATA: &str = "on x=10..12,y=10..12,z=10..12
 on x=11..13,y=11..13,z=11..13
@@ -263,15 +307,54 @@ off x=-70369..-16548,y=22648..78696,z=-1892..86821
 on x=-53470..21291,y=-120233..-33476,z=-44150..38147
 off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
 
+    const EXAMPLES: &[Example] = &[
+        Example {
+            input: TEST_DATA,
+            part_1: Some("39"),
+            part_2: None,
+        },
+        Example {
+            input: TEST_DATA_2,
+            part_1: Some("590784"),
+            part_2: None,
+        },
+        Example {
+            input: TEST_DATA_3,
+            part_1: Some("474140"),
+            part_2: Some("2758514936282235"),
+        },
+    ];
+
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_22", TEST_DATA);
-        let file_2 = temp_file_with_content("day_22_2", TEST_DATA_2);
-        let file_3 = temp_file_with_content("day_22_3", TEST_DATA_3);
-
-        assert_eq!(day_22_1(&file), 39);
-        assert_eq!(day_22_1(&file_2), 590784);
-        assert_eq!(day_22_1(&file_3), 474140);
-        assert_eq!(day_22_2(&file_3), 2758514936282235);
+        check_examples(&Day22, EXAMPLES);
+
+        let err = part_2("on x=1..2,y=1..2,z=1..2\non x=1..2,y=1..b,z=1..2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 11: invalid number: invalid digit found in string"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(s in "\\PC*") {
+            let _ = s.parse::<Cuboid>();
+        }
+
+        #[test]
+        fn display_round_trips(
+            is_on: bool,
+            ranges in prop::array::uniform6(-100_000..100_000i32),
+        ) {
+            let [x1, x2, y1, y2, z1, z2] = ranges;
+            let s = format!(
+                "{} x={}..{},y={}..{},z={}..{}",
+                if is_on { "on" } else { "off" },
+                x1, x2, y1, y2, z1, z2
+            );
+            let cuboid: Cuboid = s.parse().unwrap();
+            prop_assert_eq!(cuboid.to_string().parse::<Cuboid>(), Ok(cuboid));
+        }
     }
 }
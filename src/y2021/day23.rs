@@ -1,6 +1,11 @@
-use std::cmp::{Ordering, Reverse};
-use std::collections::{BinaryHeap, HashMap};
-use std::fs;
+use crate::error::{Error, Result};
+use crate::search::{a_star, SearchSpace};
+use crate::solution::Solution;
+use crate::trace;
+use crate::util::read_input;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::hint::black_box;
 use std::path::Path;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -23,14 +28,16 @@ impl Ord for Amphipod {
     }
 }
 
-impl From<char> for Amphipod {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Amphipod {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
         match c {
-            'A' => Amphipod::Amber,
-            'B' => Amphipod::Bronze,
-            'C' => Amphipod::Copper,
-            'D' => Amphipod::Desert,
-            _ => unreachable!("unexpected char {}", c),
+            'A' => Ok(Amphipod::Amber),
+            'B' => Ok(Amphipod::Bronze),
+            'C' => Ok(Amphipod::Copper),
+            'D' => Ok(Amphipod::Desert),
+            _ => Err(Error::unexpected_char(c)),
         }
     }
 }
@@ -115,36 +122,20 @@ fn heuristics<const N: usize>(state: State<N>) -> i32 {
     hallway_cost + rooms_cost
 }
 
-fn find_cost<const N: usize>(initial_state: State<N>) -> u64 {
-    let mut open_set = BinaryHeap::new();
-    open_set.push((Reverse(0), 0, initial_state));
-
-    let mut checked = HashMap::new();
+/// Amphipods organizing themselves, states are positions of all of them.
+struct Burrow<const N: usize>;
 
-    while let Some((_, cost, state)) = open_set.pop() {
-        if checked
-            .get(&state)
-            .map_or(false, |&old_cost| cost >= old_cost)
-        {
-            continue;
-        }
-        checked.insert(state, cost);
+impl<const N: usize> SearchSpace for Burrow<N> {
+    type State = State<N>;
 
-        // If all are in correct room, we stop
-        if state
-            .rooms
-            .iter()
-            .enumerate()
-            .all(|(n, room)| room.iter().all(|x| x.map(|x| x.correct_room()) == Some(n)))
-        {
-            return cost;
-        }
+    fn neighbours(&self, state: &State<N>) -> Vec<(State<N>, u64)> {
+        let mut moves = vec![];
 
         for (room_id, room) in state.rooms.iter().enumerate() {
             // If all are correct in this room go to the next
             if !room
                 .iter()
-                .any(|pod| pod.map_or(false, |pod| pod.correct_room() != room_id))
+                .any(|pod| pod.is_some_and(|pod| pod.correct_room() != room_id))
             {
                 continue;
             }
@@ -166,17 +157,16 @@ fn find_cost<const N: usize>(initial_state: State<N>) -> u64 {
                         .map(|i| ROOM_HALLWAYS[room_id].0 - i),
                 )
             {
-                let mut new_state = state;
+                let mut new_state = *state;
                 std::mem::swap(
                     &mut new_state.rooms[room_id][position],
                     &mut new_state.hallway[i],
                 );
-                let new_cost = cost as i32
-                    + (position as i32 + 1 + (ROOM_COLS[room_id] - HALLWAY_COLS[i]).abs())
+                let move_cost =
+                    (position as i32 + 1 + (ROOM_COLS[room_id] - HALLWAY_COLS[i]).abs())
                         * COSTS[state.rooms[room_id][position].unwrap() as usize];
-                let f_score = new_cost + heuristics(new_state);
 
-                open_set.push((Reverse(f_score), new_cost as u64, new_state));
+                moves.push((new_state, move_cost as u64));
             }
         }
 
@@ -186,7 +176,7 @@ fn find_cost<const N: usize>(initial_state: State<N>) -> u64 {
                 let room = state.rooms[pod as usize];
                 if (room
                     .iter()
-                    .all(|spot| spot.map_or(true, |other_pod| other_pod == pod)))
+                    .all(|spot| spot.is_none_or(|other_pod| other_pod == pod)))
                     && (if x >= ROOM_HALLWAYS[pod as usize].1 {
                         ROOM_HALLWAYS[pod as usize].1..x
                     } else {
@@ -199,58 +189,152 @@ fn find_cost<const N: usize>(initial_state: State<N>) -> u64 {
                         .find(|&spot| room[spot].is_none())
                         .unwrap();
 
-                    let mut new_state = state;
+                    let mut new_state = *state;
                     std::mem::swap(
                         &mut new_state.rooms[pod as usize][y],
                         &mut new_state.hallway[x],
                     );
 
-                    let new_cost = cost as i32
-                        + (y as i32
-                            + 1
-                            + (ROOM_COLS[pod as usize] - HALLWAY_COLS[x as usize]).abs())
+                    let move_cost =
+                        (y as i32 + 1 + (ROOM_COLS[pod as usize] - HALLWAY_COLS[x]).abs())
                             * COSTS[pod as usize];
 
-                    let f_score = new_cost + heuristics(new_state);
-
-                    open_set.push((Reverse(f_score), new_cost as u64, new_state));
+                    moves.push((new_state, move_cost as u64));
                 }
             }
         }
+
+        moves
+    }
+
+    // If all are in correct room, we stop
+    fn is_goal(&self, state: &State<N>) -> bool {
+        state
+            .rooms
+            .iter()
+            .enumerate()
+            .all(|(n, room)| room.iter().all(|x| x.map(|x| x.correct_room()) == Some(n)))
+    }
+
+    fn heuristic(&self, state: &State<N>) -> u64 {
+        heuristics(*state) as u64
     }
-    panic!("failed to find cost")
 }
 
-fn parse_data<const N: usize>(lines: Vec<&str>) -> State<N> {
+fn find_cost<const N: usize>(initial_state: State<N>) -> Result<u64> {
+    let _span = trace::span("day23::find_cost");
+    a_star(&Burrow::<N>, initial_state)
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::no_solution("failed to find cost"))
+}
+
+fn parse_data<const N: usize>(lines: Vec<&str>) -> Result<State<N>> {
     let mut initial_state: State<N> = State::new();
-    for (n, line) in lines.iter().skip(2).enumerate() {
-        for (m, c) in line.chars().filter(|c| c.is_ascii_uppercase()).enumerate() {
-            initial_state.rooms[m][n] = Some(c.into());
+    let room_lines: Vec<&str> = lines
+        .iter()
+        .skip(2)
+        .filter(|line| line.contains(|c: char| c.is_ascii_alphabetic()))
+        .copied()
+        .collect();
+    if room_lines.len() != N {
+        return Err(Error::parse(format!(
+            "expected {} rows of amphipods, got {}",
+            N,
+            room_lines.len()
+        )));
+    }
+
+    for (n, line) in room_lines.iter().enumerate() {
+        let pods = line
+            .char_indices()
+            .filter(|(_, c)| c.is_ascii_alphabetic())
+            .map(|(col, c)| Amphipod::try_from(c).map_err(|e| e.at(n + 3, col + 1)));
+        let mut count = 0;
+        for (m, pod) in pods.enumerate() {
+            if m == 4 {
+                return Err(Error::parse("too many amphipods").at_line(n + 3));
+            }
+            initial_state.rooms[m][n] = Some(pod?);
+            count += 1;
+        }
+        if count != 4 {
+            return Err(Error::parse("too few amphipods").at_line(n + 3));
+        }
+    }
+
+    // Search relies on every room having exactly one kind of amphipods at the end.
+    for kind in [
+        Amphipod::Amber,
+        Amphipod::Bronze,
+        Amphipod::Copper,
+        Amphipod::Desert,
+    ] {
+        let count = initial_state
+            .rooms
+            .iter()
+            .flatten()
+            .filter(|&&pod| pod == Some(kind))
+            .count();
+        if count != N {
+            return Err(Error::no_solution(format!(
+                "expected {} of {:?}, got {}",
+                N, kind, count
+            )));
         }
     }
-    initial_state
+    Ok(initial_state)
 }
 
-pub fn day_23_1<P: AsRef<Path>>(input_file: P) -> u64 {
-    let data = fs::read_to_string(input_file).unwrap();
-    let lines: Vec<&str> = data.lines().collect();
-    let initial_state = parse_data::<2>(lines);
+pub fn part_1(input: &str) -> Result<u64> {
+    let lines: Vec<&str> = input.lines().collect();
+    let initial_state = parse_data::<2>(lines)?;
     find_cost::<2>(initial_state)
 }
 
-pub fn day_23_2<P: AsRef<Path>>(input_file: P) -> u64 {
-    let data = fs::read_to_string(input_file).unwrap();
-    let mut lines: Vec<&str> = data.lines().collect();
+pub fn part_2(input: &str) -> Result<u64> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    if lines.len() < 3 {
+        return Err(Error::parse("missing rooms"));
+    }
     lines.insert(3, "#D#C#B#A#");
     lines.insert(4, "#D#B#A#C#");
-    let initial_state = parse_data::<4>(lines);
+    let initial_state = parse_data::<4>(lines)?;
     find_cost::<4>(initial_state)
 }
 
+pub fn day_23_1<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_23_2<P: AsRef<Path>>(input_file: P) -> Result<u64> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn day(&self) -> u16 {
+        23
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(parse_data::<2>(input.lines().collect())?);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::day23::{day_23_1, day_23_2};
-    use crate::util::temp_file_with_content;
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day23::Day23;
 
     const TEST_DATA: &str = "#############
 #...........#
@@ -258,11 +342,14 @@ mod test {
   #A#D#C#A#
   #########";
 
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("12521"),
+        part_2: Some("44169"),
+    }];
+
     #[test]
     fn test() {
-        let file = temp_file_with_content("day_23", TEST_DATA);
-
-        assert_eq!(day_23_1(&file), 12521);
-        assert_eq!(day_23_2(&file), 44169);
+        check_examples(&Day23, EXAMPLES);
     }
 }
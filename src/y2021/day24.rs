@@ -0,0 +1,181 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, strip_prefix};
+use std::path::Path;
+
+// This is hacky solution based on some tips from Reddit.
+// Apparently all inputs had similar characteristics and I was to tired...
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let (_, max) = find_min_max(input)?;
+    Ok(max)
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let (min, _) = find_min_max(input)?;
+    Ok(min)
+}
+
+// Every digit is processed by the same block of instructions with a few varying arguments.
+const BLOCK_LEN: usize = 18;
+
+fn find_min_max(input: &str) -> Result<(usize, usize)> {
+    let mut max_digits = [0; 14];
+    let mut min_digits = [0; 14];
+
+    let mut stack = vec![];
+
+    let inst = input
+        .split("inp w\n")
+        .skip(1)
+        .map(|s| s.lines().collect::<Vec<_>>());
+
+    for (idx, instructions) in inst.enumerate() {
+        if idx == max_digits.len() {
+            return Err(Error::parse("too many input instructions").at_line(idx * BLOCK_LEN + 1));
+        }
+        // Input instruction is not part of the block, hence the shift by one.
+        let line_no = |line: usize| idx * BLOCK_LEN + line + 2;
+        let arg = |line: usize, prefix: &str| -> Result<i8> {
+            let line_no = line_no(line);
+            let instruction = instructions
+                .get(line)
+                .ok_or_else(|| Error::parse("block is too short").at_line(line_no))?;
+            strip_prefix(instruction, prefix)
+                .and_then(|arg| Ok(arg.parse()?))
+                .map_err(|e| e.at_line(line_no))
+        };
+
+        let z_division = arg(3, "div z ")?;
+
+        if z_division == 1 {
+            let f = arg(14, "add y ")?;
+            stack.push((idx, f));
+        } else if z_division == 26 {
+            let (mut prev_idx, x) = stack
+                .pop()
+                .ok_or_else(|| Error::no_solution("unbalanced z divisions"))?;
+            let out_of_range = || Error::parse("digit difference out of range").at_line(line_no(4));
+            let mut diff = x.checked_add(arg(4, "add x ")?).ok_or_else(out_of_range)?;
+
+            let mut idx = idx;
+            if diff < 0 {
+                core::mem::swap(&mut idx, &mut prev_idx);
+                diff = diff.checked_neg().ok_or_else(out_of_range)?;
+            }
+            if diff > 8 {
+                return Err(Error::no_solution(format!(
+                    "digits {} and {} cannot differ by {}",
+                    prev_idx, idx, diff
+                )));
+            }
+
+            max_digits[idx] = 9;
+            max_digits[prev_idx] = 9 - diff as u8;
+            min_digits[idx] = 1 + diff as u8;
+            min_digits[prev_idx] = 1;
+        } else {
+            return Err(Error::no_solution(format!(
+                "unexpected z division: {}",
+                z_division
+            )));
+        }
+    }
+    if !stack.is_empty() || max_digits.contains(&0) {
+        return Err(Error::no_solution("unbalanced z divisions"));
+    }
+
+    let max = to_num(&max_digits);
+    let min = to_num(&min_digits);
+    Ok((min, max))
+}
+
+fn to_num(digits: &[u8]) -> usize {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &x)| x as usize * 10usize.pow(i as u32))
+        .sum::<usize>()
+}
+
+pub fn day_24_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_24_2<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> u16 {
+        24
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, _input: &str) -> Result<()> {
+        // Instructions are interpreted while solving, so there is nothing to parse upfront.
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::solution::Solution;
+    use crate::y2021::day24::Day24;
+
+    /// Block of instructions processing a digit, with its varying arguments.
+    fn block(z_division: i32, add_x: i32, add_y: i32) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y\n",
+            z_division, add_x, add_y
+        )
+    }
+
+    /// Program requiring digits of each pair to differ by the given amount.
+    fn program(diffs: &[i32]) -> String {
+        diffs
+            .iter()
+            .map(|diff| block(1, 12, 5) + &block(26, diff - 5, 3))
+            .collect()
+    }
+
+    #[test]
+    fn test() {
+        let input = program(&[2, -3, 0, 5, -1, 8, -8]);
+        assert_eq!(Day24.part_1(&input), Ok("79969949981991".to_string()));
+        assert_eq!(Day24.part_2(&input), Ok("13411116211991".to_string()));
+
+        let too_far = program(&[9, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            Day24.part_1(&too_far),
+            Err(Error::no_solution("digits 0 and 1 cannot differ by 9"))
+        );
+
+        // Arguments fit, but their sum does not.
+        let overflow = block(1, 12, 127) + &block(26, 127, 3);
+        assert_eq!(
+            Day24.part_1(&overflow),
+            Err(Error::parse("digit difference out of range").at_line(24))
+        );
+        let underflow = block(1, 12, -100) + &block(26, -28, 3);
+        assert_eq!(
+            Day24.part_2(&underflow),
+            Err(Error::parse("digit difference out of range").at_line(24))
+        );
+        let too_large = block(1, 12, 128);
+        assert!(Day24.part_1(&too_large).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{read_input, Grid, Pos};
+use std::hint::black_box;
+use std::path::Path;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Cucumber {
+    East,
+    South,
+}
+
+impl Cucumber {
+    fn direction(&self) -> (isize, isize) {
+        match self {
+            Cucumber::East => (0, 1),
+            Cucumber::South => (1, 0),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let mut cucumbers = parse_data(input)?;
+
+    let mut moved = true;
+    let mut round = 0;
+
+    while moved {
+        moved = false;
+
+        if move_cucumbers(Cucumber::East, &mut cucumbers) {
+            moved = true
+        }
+        if move_cucumbers(Cucumber::South, &mut cucumbers) {
+            moved = true
+        }
+
+        round += 1;
+    }
+    Ok(round)
+}
+
+pub fn part_2(_input: &str) -> Result<String> {
+    Ok(String::from("Done!"))
+}
+
+fn move_cucumbers(cuc_type: Cucumber, cucumbers: &mut Grid<Option<Cucumber>>) -> bool {
+    // Herd moves at once, so all moves are decided before any is made.
+    let moves: Vec<(Pos, Pos)> = cucumbers
+        .enumerate()
+        .filter(|&(_, cuc)| *cuc == Some(cuc_type))
+        .map(|(pos, _)| (pos, cucumbers.wrapping_offset(pos, cuc_type.direction())))
+        .filter(|&(_, next_pos)| cucumbers[next_pos].is_none())
+        .collect();
+
+    for &(pos, next_pos) in &moves {
+        cucumbers[pos] = None;
+        cucumbers[next_pos] = Some(cuc_type);
+    }
+
+    !moves.is_empty()
+}
+
+fn parse_data(data: &str) -> Result<Grid<Option<Cucumber>>> {
+    Grid::parse(data, |ch| match ch {
+        'v' => Ok(Some(Cucumber::South)),
+        '>' => Ok(Some(Cucumber::East)),
+        '.' => Ok(None),
+        _ => Err(Error::unexpected_char(ch)),
+    })
+}
+
+pub fn day_25_1<P: AsRef<Path>>(input_file: P) -> Result<usize> {
+    part_1(&read_input(input_file)?)
+}
+
+pub fn day_25_2<P: AsRef<Path>>(input_file: P) -> Result<String> {
+    part_2(&read_input(input_file)?)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> u16 {
+        25
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        black_box(parse_data(input)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::{check_examples, Example};
+    use crate::y2021::day25::Day25;
+
+    const TEST_DATA: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    const EXAMPLES: &[Example] = &[Example {
+        input: TEST_DATA,
+        part_1: Some("58"),
+        part_2: None,
+    }];
+
+    #[test]
+    fn test() {
+        check_examples(&Day25, EXAMPLES);
+    }
+}